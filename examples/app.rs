@@ -56,6 +56,16 @@ pub mod example {
                 &self.content, 
                 params.transform(self.tf.apply_matrix(parent_matrix)));
         }
+
+        pub fn draw_culled(&mut self, ctx: &Context, canvas: &mut Canvas, params: DrawParam, camera: &camera2d_ggez::Camera) {
+            let visible = match self.content.dimensions(ctx) {
+                Some(dimensions) => camera.is_transform_visible(self.tf, dimensions, 0.),
+                None => true,
+            };
+            if visible {
+                self.draw(canvas, params, &camera.to_matrix());
+            }
+        }
     }
 
     pub struct TextBox {
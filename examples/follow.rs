@@ -3,10 +3,10 @@ use ggez::{
     GameResult,
     event::EventHandler, 
     winit::event::VirtualKeyCode, 
-    glam::Vec2, mint::Point2, 
-    graphics::{ Color, Canvas, DrawParam, Image, Mesh, DrawMode, FillOptions, Rect, Sampler }
+    mint::Point2, 
+    graphics::{ Color, Canvas, DrawParam, Image, Mesh, DrawMode, FillOptions, Rect, Sampler, StrokeOptions }
 };
-use camera2d_ggez::Camera;
+use camera2d_ggez::{Anchor, Camera, CameraFollow, FollowTarget, FollowZones, LookAhead, Viewport};
 
 mod app;
 use app::example;
@@ -17,6 +17,7 @@ fn main() -> GameResult {
 
 struct FollowExample {
     camera: Camera,
+    follow: CameraFollow,
     player: example::DrawableWrapper<Mesh>,
     map: example::DrawableWrapper<Image>,
     text: example::TextBox,
@@ -40,16 +41,23 @@ impl FollowExample {
                 ..Default::default() }};
 
         let mut camera = Camera::default();
-        camera.set_offset(Vec2::from(ctx.gfx.drawable_size()) / 2.);
+        camera.set_viewport(Viewport::from_context(ctx));
+        camera.set_anchor(Anchor::CENTER);
         camera.set_position(player.tf.dest);
 
         let text = example::TextBox::new(
             ctx, 
-            "Press Left or Right to rotate player\nPress space to move player\nScrool to zoom\nPress A or D to rotate camera", 
-            Rect::new(20., 20., 350., 90.));
+            "Press Left or Right to rotate player\nPress space to move player\nScrool to zoom\nPress A or D to rotate camera\nPress P to toggle pixel perfect", 
+            Rect::new(20., 20., 350., 110.));
 
         FollowExample { 
             camera, 
+            follow: CameraFollow {
+                zones: Some(FollowZones::new(
+                    Rect::new(0.4, 0.4, 0.2, 0.2),
+                    Rect::new(0.15, 0.15, 0.7, 0.7))),
+                look_ahead: Some(LookAhead::new(0.3, 200.)),
+                ..CameraFollow::new(0.15) },
             player,
             map,
             text,
@@ -92,27 +100,37 @@ impl EventHandler for FollowExample {
                 x: self.player.tf.dest.x + delta.x,
                 y: self.player.tf.dest.y + delta.y,
             };
-            self.camera.set_position(self.player.tf.dest);
-            //self.camera.move_by_world_coords(delta);
         }
 
+        let dt = ctx.time.delta().as_secs_f32();
+        self.follow.update(&mut self.camera, FollowTarget::new(self.player.tf.dest), dt);
+
         if ctx.keyboard.is_key_pressed(Key::A) { self.camera.rotate(0.01); }
         if ctx.keyboard.is_key_pressed(Key::D) { self.camera.rotate(-0.01); }
+        if ctx.keyboard.is_key_just_pressed(Key::P) {
+            self.camera.set_pixel_perfect(!self.camera.pixel_perfect);
+        }
 
         Ok(())
     }
 
     fn draw(&mut self, ctx: &mut Context) -> GameResult {
         let mut canvas = Canvas::from_frame(ctx, Color::from_rgb(30, 30, 30));
-        if self.camera.get_zoom().gt(&[0.6, 0.6].into()) {
+        if self.camera.pixel_perfect {
             canvas.set_sampler(Sampler::nearest_clamp());
         }
 
         let params = DrawParam::default();
-        let camera_matrix = self.camera.to_matrix();
 
-        self.map.draw(&mut canvas, params, &camera_matrix);
-        self.player.draw(&mut canvas, params, &camera_matrix);
+        self.map.draw_culled(ctx, &mut canvas, params, &self.camera);
+        self.player.draw_culled(ctx, &mut canvas, params, &self.camera);
+        if let Some(zones) = self.follow.zones {
+            for rect in zones.screen_rects(&self.camera) {
+                let outline = Mesh::new_rectangle(
+                    ctx, DrawMode::Stroke(StrokeOptions::default()), rect, Color::from_rgba(255, 255, 255, 80))?;
+                canvas.draw(&outline, params);
+            }
+        }
         self.text.draw(ctx, &mut canvas);
 
         canvas.finish(ctx)?;
@@ -120,8 +138,13 @@ impl EventHandler for FollowExample {
         Ok(())
     }
 
-    fn mouse_wheel_event(&mut self, ctx: &mut Context, _x: f32, y: f32) -> GameResult {
-        self.camera.zoom_center(ctx, [1. + 0.1 * y, 1. + 0.1 * y]);
+    fn mouse_wheel_event(&mut self, _ctx: &mut Context, _x: f32, y: f32) -> GameResult {
+        self.camera.zoom_center([1. + 0.1 * y, 1. + 0.1 * y]);
+        Ok(())
+    }
+
+    fn resize_event(&mut self, _ctx: &mut Context, width: f32, height: f32) -> GameResult {
+        self.camera.handle_resize(self.camera.viewport.size, [width, height].into());
         Ok(())
     }
 }
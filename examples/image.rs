@@ -1,12 +1,10 @@
 use ggez::{
     event::{EventHandler, MouseButton},
-    glam::Vec2,
     graphics::{Canvas, Color, DrawParam, Image, Rect},
-    mint::Point2,
     winit::event::VirtualKeyCode,
     Context, GameResult,
 };
-use camera2d_ggez::Camera;
+use camera2d_ggez::{Camera, CameraBounds, FitMode, ResizePolicy, Viewport, ZoomLimits};
 
 mod app;
 use app::example;
@@ -23,36 +21,42 @@ struct ImageExample {
 
 impl ImageExample {
     fn new(ctx: &Context) -> Self {
-        let mut image = example::DrawableWrapper {
+        let image = example::DrawableWrapper {
             content: Image::from_path(ctx, "/subaru-duck.png").unwrap(),
             tf: camera2d_ggez::Transform::default(),
         };
 
-        image.tf = camera2d_ggez::Transform {
-            dest: (Vec2::from(ctx.gfx.drawable_size()) / 2.).into(),
-            offset: Point2 {
-                x: image.content.width() as f32 / 2.,
-                y: image.content.height() as f32 / 2.,
-            },
-            ..Default::default()
-        };
-
         let text = example::TextBox::new(
             ctx,
-            "Hold and drag / hold space to move camera\nScrool to zoom",
-            Rect::new(10., 10., 380., 40.),
+            "Hold and drag / hold space to move camera\nScrool to zoom\nPress F to fit image",
+            Rect::new(10., 10., 380., 60.),
         );
 
+        let mut camera = Camera::default();
+        camera.set_viewport(Viewport::from_context(ctx));
+        camera.resize_policy = ResizePolicy::Fit;
+        camera.set_zoom_limits(ZoomLimits::new([0.25, 0.25], [8., 8.]));
+        camera.set_bounds(Some(CameraBounds::new(Self::image_rect(&image.content))));
+        camera.frame_rect(Self::image_rect(&image.content), 0., FitMode::Contain);
+
         ImageExample {
-            camera: Camera::default(),
+            camera,
             text,
             image,
         }
     }
+
+    fn image_rect(image: &Image) -> Rect {
+        Rect::new(0., 0., image.width() as f32, image.height() as f32)
+    }
 }
 
 impl EventHandler for ImageExample {
-    fn update(&mut self, _ctx: &mut Context) -> GameResult {
+    fn update(&mut self, ctx: &mut Context) -> GameResult {
+        if ctx.keyboard.is_key_just_pressed(VirtualKeyCode::F) {
+            let rect = Self::image_rect(&self.image.content);
+            self.camera.frame_rect(rect, 20., FitMode::Contain);
+        }
         Ok(())
     }
 
@@ -92,4 +96,10 @@ impl EventHandler for ImageExample {
             .zoom_at_screen_coords(ctx.mouse.position(), [1. + 0.1 * y, 1. + 0.1 * y]);
         Ok(())
     }
+
+    fn resize_event(&mut self, _ctx: &mut Context, width: f32, height: f32) -> GameResult {
+        self.camera
+            .handle_resize(self.camera.viewport.size, [width, height].into());
+        Ok(())
+    }
 }
@@ -1,10 +1,11 @@
 use ggez::{
     event::{EventHandler, MouseButton},
     graphics::{Canvas, Color, DrawMode, DrawParam, FillOptions, Mesh, Rect},
+    mint::Point2,
     winit::event::VirtualKeyCode,
     Context, GameResult,
 };
-use camera2d_ggez::Camera;
+use camera2d_ggez::{Camera, CameraShake, CameraTweener, Easing, Tween, Viewport};
 
 mod app;
 use app::example;
@@ -15,6 +16,9 @@ fn main() -> GameResult {
 
 struct MeshExample {
     camera: Camera,
+    shake: CameraShake,
+    tweener: CameraTweener,
+    rotate_pivot: Option<Point2<f32>>,
     text: example::TextBox,
     mesh1: example::DrawableWrapper<Mesh>,
     mesh2: example::DrawableWrapper<Mesh>,
@@ -78,12 +82,18 @@ impl MeshExample {
 
         let text = example::TextBox::new(
             ctx,
-            "Hold and drag / hold space to move camera\nScrool to zoom",
-            Rect::new(10., 10., 380., 40.),
+            "Hold and drag / hold space to move camera\nScrool to zoom\nPress Q or E / right drag to rotate camera\nPress T to shake\nPress R to reset camera",
+            Rect::new(10., 10., 420., 100.),
         );
 
+        let mut camera = Camera::default();
+        camera.set_viewport(Viewport::from_context(ctx));
+
         MeshExample {
-            camera: Camera::default(),
+            camera,
+            shake: CameraShake::default(),
+            tweener: CameraTweener::new(),
+            rotate_pivot: None,
             text,
             mesh1,
             mesh2,
@@ -94,7 +104,27 @@ impl MeshExample {
 }
 
 impl EventHandler for MeshExample {
-    fn update(&mut self, _ctx: &mut Context) -> GameResult {
+    fn update(&mut self, ctx: &mut Context) -> GameResult {
+        if ctx.keyboard.is_key_pressed(VirtualKeyCode::Q) {
+            self.camera.rotate(-0.01);
+        }
+        if ctx.keyboard.is_key_pressed(VirtualKeyCode::E) {
+            self.camera.rotate(0.01);
+        }
+        if ctx.keyboard.is_key_just_pressed(VirtualKeyCode::T) {
+            self.shake.add_trauma(0.5);
+        }
+        if ctx.keyboard.is_key_just_pressed(VirtualKeyCode::R) {
+            self.tweener.play(Tween {
+                position: Some([0., 0.].into()),
+                zoom: Some([1., 1.].into()),
+                rotation: Some(0.),
+                ..Tween::new(0.6, Easing::CubicInOut)
+            });
+        }
+        let dt = ctx.time.delta().as_secs_f32();
+        self.tweener.update(&mut self.camera, dt);
+        self.shake.update(dt);
         Ok(())
     }
 
@@ -103,15 +133,15 @@ impl EventHandler for MeshExample {
         let params = DrawParam::default();
 
         self.mesh1
-            .draw(&mut canvas, params, &self.camera.to_matrix());
+            .draw(&mut canvas, params, &self.shake.to_matrix(&self.camera));
 
-        let camera_matrix = self.camera.to_matrix();
+        let camera_matrix = self.shake.to_matrix(&self.camera);
         canvas.draw(
             &self.mesh2.content,
             params.transform(self.mesh2.tf.apply_matrix(&camera_matrix)),
         );
 
-        canvas.draw(&self.mesh3, self.camera);
+        canvas.draw(&self.mesh3, self.shake.draw_param(&self.camera));
         canvas.draw(&self.mesh4, params);
 
         self.text.draw(ctx, &mut canvas);
@@ -134,6 +164,35 @@ impl EventHandler for MeshExample {
         {
             self.camera.move_by_screen_coords([dx, dy]);
         }
+        if let Some(pivot) = self.rotate_pivot {
+            self.camera.rotate_at_screen_coords(pivot, dx * 0.01);
+        }
+        Ok(())
+    }
+
+    fn mouse_button_down_event(
+        &mut self,
+        _ctx: &mut Context,
+        button: MouseButton,
+        x: f32,
+        y: f32,
+    ) -> GameResult {
+        if button == MouseButton::Right {
+            self.rotate_pivot = Some(Point2 { x, y });
+        }
+        Ok(())
+    }
+
+    fn mouse_button_up_event(
+        &mut self,
+        _ctx: &mut Context,
+        button: MouseButton,
+        _x: f32,
+        _y: f32,
+    ) -> GameResult {
+        if button == MouseButton::Right {
+            self.rotate_pivot = None;
+        }
         Ok(())
     }
 
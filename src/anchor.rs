@@ -0,0 +1,40 @@
+use ggez::mint::{Point2, Vector2};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anchor {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Default for Anchor {
+    fn default() -> Self {
+        Anchor::TOP_LEFT
+    }
+}
+
+impl Anchor {
+    pub const TOP_LEFT: Anchor = Anchor { x: 0., y: 0. };
+    pub const TOP_CENTER: Anchor = Anchor { x: 0.5, y: 0. };
+    pub const TOP_RIGHT: Anchor = Anchor { x: 1., y: 0. };
+    pub const CENTER_LEFT: Anchor = Anchor { x: 0., y: 0.5 };
+    pub const CENTER: Anchor = Anchor { x: 0.5, y: 0.5 };
+    pub const CENTER_RIGHT: Anchor = Anchor { x: 1., y: 0.5 };
+    pub const BOTTOM_LEFT: Anchor = Anchor { x: 0., y: 1. };
+    pub const BOTTOM_CENTER: Anchor = Anchor { x: 0.5, y: 1. };
+    pub const BOTTOM_RIGHT: Anchor = Anchor { x: 1., y: 1. };
+
+    pub fn new(x: f32, y: f32) -> Self {
+        Anchor { x, y }
+    }
+
+    pub fn resolve<V>(&self, size: V) -> Point2<f32>
+    where
+        V: Into<Vector2<f32>>,
+    {
+        let size: Vector2<f32> = size.into();
+        Point2 {
+            x: self.x * size.x,
+            y: self.y * size.y,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use ggez::{glam::Vec2, graphics::Rect};
+
+use super::camera::Camera;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundsAlign {
+    Center,
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CameraBounds {
+    pub rect: Rect,
+    pub clamp_zoom: bool,
+    pub align: BoundsAlign,
+}
+
+impl CameraBounds {
+    pub fn new(rect: Rect) -> Self {
+        CameraBounds {
+            rect,
+            clamp_zoom: false,
+            align: BoundsAlign::Center,
+        }
+    }
+
+    pub fn confine(&self, camera: &mut Camera) {
+        let (mut min, mut max) = view_extents(camera);
+
+        // Zoom limits take precedence over `clamp_zoom`; a view they keep larger
+        // than the rect, or a rect with no area, is placed according to `align`.
+        if self.clamp_zoom && self.rect.w > 0. && self.rect.h > 0. {
+            let factor = ((max.x - min.x) / self.rect.w).max((max.y - min.y) / self.rect.h);
+            if factor > 1. {
+                camera.scale =
+                    camera.clamp_zoom([camera.scale.x * factor, camera.scale.y * factor]);
+                (min, max) = view_extents(camera);
+            }
+        }
+
+        camera.position.x = self.clamp_axis(
+            camera.position.x,
+            self.rect.left(),
+            self.rect.right(),
+            min.x,
+            max.x,
+        );
+        camera.position.y = self.clamp_axis(
+            camera.position.y,
+            self.rect.top(),
+            self.rect.bottom(),
+            min.y,
+            max.y,
+        );
+    }
+
+    fn clamp_axis(&self, position: f32, start: f32, end: f32, min: f32, max: f32) -> f32 {
+        let lower = start - min;
+        let upper = end - max;
+        if lower > upper {
+            return match self.align {
+                BoundsAlign::Center => (lower + upper) / 2.,
+                BoundsAlign::Start => lower,
+                BoundsAlign::End => upper,
+            };
+        }
+        position.clamp(lower, upper)
+    }
+}
+
+// World-space extents of the visible area relative to the camera position.
+fn view_extents(camera: &Camera) -> (Vec2, Vec2) {
+    let position = Vec2::from(camera.position);
+    camera.visible_corners().iter().fold(
+        (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+        |(min, max), corner| {
+            let relative = Vec2::from(*corner) - position;
+            (min.min(relative), max.max(relative))
+        },
+    )
+}
@@ -1,27 +1,50 @@
 use ggez::{
-    glam::{Mat4, Vec3},
-    graphics::DrawParam,
+    glam::{Mat4, Vec2, Vec3},
+    graphics::{DrawParam, Rect},
     mint::{Point2, Vector2},
-    Context,
 };
 
-use super::transform::Transform;
+use super::{
+    anchor::Anchor,
+    bounds::CameraBounds,
+    confiner::PolygonConfiner,
+    geometry,
+    transform::Transform,
+    viewport::{ResizePolicy, Viewport, VirtualResolution},
+    zoom::{self, ZoomLimits},
+};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Camera {
+    pub anchor: Anchor,
     pub offset: Point2<f32>,
     pub rotation: f32,
     pub scale: Vector2<f32>,
     pub position: Point2<f32>,
+    pub viewport: Viewport,
+    pub resize_policy: ResizePolicy,
+    pub virtual_resolution: Option<VirtualResolution>,
+    pub pixel_perfect: bool,
+    pub bounds: Option<CameraBounds>,
+    pub confiner: Option<PolygonConfiner>,
+    pub zoom_limits: ZoomLimits,
 }
 
 impl Default for Camera {
     fn default() -> Self {
         Camera {
+            anchor: Anchor::default(),
             offset: Point2 { x: 0., y: 0. },
             rotation: 0.,
             scale: Vector2 { x: 1., y: 1. },
             position: Point2 { x: 0., y: 0. },
+            viewport: Viewport::default(),
+            resize_policy: ResizePolicy::default(),
+            virtual_resolution: None,
+            pixel_perfect: false,
+            bounds: None,
+            confiner: None,
+            zoom_limits: ZoomLimits::default(),
         }
     }
 }
@@ -33,20 +56,36 @@ impl Camera {
         V: Into<Vector2<f32>>,
     {
         Camera {
+            anchor: Anchor::default(),
             offset: offset.into(),
             rotation,
             scale: scale.into(),
             position: position.into(),
+            viewport: Viewport::default(),
+            resize_policy: ResizePolicy::default(),
+            virtual_resolution: None,
+            pixel_perfect: false,
+            bounds: None,
+            confiner: None,
+            zoom_limits: ZoomLimits::default(),
         }
     }
+
     pub fn to_matrix(&self) -> Mat4 {
+        let screen_scale = self.screen_scale();
+        let scale = self.render_scale();
         let (sinr, cosr) = self.rotation.sin_cos();
-        let m00 = cosr * self.scale.x;
-        let m01 = -sinr * self.scale.y;
-        let m10 = sinr * self.scale.x;
-        let m11 = cosr * self.scale.y;
-        let m03 = self.position.x * (-m00) - self.position.y * m01 + self.offset.x;
-        let m13 = self.position.y * (-m11) - self.position.x * m10 + self.offset.y;
+        let m00 = cosr * scale.x * screen_scale.x;
+        let m01 = -sinr * scale.y * screen_scale.x;
+        let m10 = sinr * scale.x * screen_scale.y;
+        let m11 = cosr * scale.y * screen_scale.y;
+        let screen_anchor = self.screen_anchor();
+        let mut m03 = self.position.x * (-m00) - self.position.y * m01 + screen_anchor.x;
+        let mut m13 = self.position.y * (-m11) - self.position.x * m10 + screen_anchor.y;
+        if self.pixel_perfect {
+            m03 = m03.round();
+            m13 = m13.round();
+        }
 
         Mat4::from_cols_array(&[
             m00, m01, 0.0, m03, //
@@ -93,6 +132,172 @@ impl Camera {
         }
     }
 
+    pub fn world_to_window_coords<P>(&self, point: P) -> Point2<f32>
+    where
+        P: Into<Point2<f32>>,
+    {
+        self.viewport
+            .drawable_to_window(self.world_to_screen_coords(point))
+    }
+
+    pub fn window_to_world_coords<P>(&self, point: P) -> Point2<f32>
+    where
+        P: Into<Point2<f32>>,
+    {
+        self.screen_to_world_coords(self.viewport.window_to_drawable(point))
+    }
+
+    pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        self.viewport.set_scale_factor(scale_factor);
+    }
+
+    pub fn visible_corners(&self) -> [Point2<f32>; 4] {
+        self.visible_quad(0.).map(Point2::from)
+    }
+
+    pub fn visible_rect(&self) -> Rect {
+        let corners = self.visible_corners();
+        let (mut min, mut max) = (corners[0], corners[0]);
+        for corner in &corners[1..] {
+            min.x = min.x.min(corner.x);
+            min.y = min.y.min(corner.y);
+            max.x = max.x.max(corner.x);
+            max.y = max.y.max(corner.y);
+        }
+        Rect::new(min.x, min.y, max.x - min.x, max.y - min.y)
+    }
+
+    pub fn is_point_visible<P>(&self, point: P, margin: f32) -> bool
+    where
+        P: Into<Point2<f32>>,
+    {
+        let point: Point2<f32> = point.into();
+        geometry::point_in_convex(point.into(), &self.visible_quad(margin))
+    }
+
+    pub fn is_rect_visible(&self, rect: Rect, margin: f32) -> bool {
+        let corners = [
+            Vec2::new(rect.left(), rect.top()),
+            Vec2::new(rect.right(), rect.top()),
+            Vec2::new(rect.right(), rect.bottom()),
+            Vec2::new(rect.left(), rect.bottom()),
+        ];
+        geometry::convex_polygons_intersect(&corners, &self.visible_quad(margin))
+    }
+
+    pub fn is_circle_visible<P>(&self, center: P, radius: f32, margin: f32) -> bool
+    where
+        P: Into<Point2<f32>>,
+    {
+        let center: Point2<f32> = center.into();
+        geometry::circle_intersects_convex(center.into(), radius, &self.visible_quad(margin))
+    }
+
+    pub fn is_transform_visible<T>(&self, object: T, dimensions: Rect, margin: f32) -> bool
+    where
+        T: Into<Transform>,
+    {
+        let object: Transform = object.into();
+        let matrix = object.to_matrix();
+        let corners = [
+            (dimensions.left(), dimensions.top()),
+            (dimensions.right(), dimensions.top()),
+            (dimensions.right(), dimensions.bottom()),
+            (dimensions.left(), dimensions.bottom()),
+        ]
+        .map(|(x, y)| matrix.transform_point3(Vec3::new(x, y, 0.)).truncate());
+        geometry::convex_polygons_intersect(&corners, &self.visible_quad(margin))
+    }
+
+    // Viewport corners grown by `margin` screen pixels, mapped to world space.
+    fn visible_quad(&self, margin: f32) -> [Vec2; 4] {
+        let inverse_matrix = self.to_matrix().inverse();
+        let visible = self.visible_screen_rect();
+        let x = visible.x - margin;
+        let y = visible.y - margin;
+        let w = visible.w + margin * 2.;
+        let h = visible.h + margin * 2.;
+        [(x, y), (x + w, y), (x + w, y + h), (x, y + h)].map(|(x, y)| {
+            inverse_matrix
+                .transform_point3(Vec3::new(x, y, 0.))
+                .truncate()
+        })
+    }
+
+    pub fn set_bounds(&mut self, bounds: Option<CameraBounds>) {
+        self.bounds = bounds;
+        self.confine();
+    }
+
+    pub fn set_confiner(&mut self, confiner: Option<PolygonConfiner>) {
+        self.confiner = confiner;
+        self.confine();
+    }
+
+    pub fn confine(&mut self) {
+        if let Some(bounds) = self.bounds {
+            bounds.confine(self);
+        }
+        if let Some(confiner) = self.confiner.clone() {
+            confiner.confine(self);
+        }
+    }
+
+    pub fn set_viewport(&mut self, viewport: Viewport) {
+        self.viewport = viewport;
+        self.confine();
+    }
+
+    pub fn resize_viewport<V>(&mut self, size: V)
+    where
+        V: Into<Vector2<f32>>,
+    {
+        self.viewport.set_size(size);
+        self.confine();
+    }
+
+    pub fn handle_resize<V>(&mut self, old: V, new: V)
+    where
+        V: Into<Vector2<f32>>,
+    {
+        let old: Vector2<f32> = old.into();
+        let new: Vector2<f32> = new.into();
+
+        let pin = |size: Vector2<f32>| match self.resize_policy {
+            ResizePolicy::KeepTopLeft => self.viewport.origin,
+            _ => Point2 {
+                x: self.viewport.origin.x + size.x / 2.,
+                y: self.viewport.origin.y + size.y / 2.,
+            },
+        };
+        let (old_pin, new_pin) = (pin(old), pin(new));
+
+        self.viewport.size = old;
+        let world_pin = self.screen_to_world_coords(old_pin);
+        let old_logical = self.logical_size();
+
+        // Measured in logical units, since a virtual resolution already rescales
+        // the screen for the new size.
+        self.viewport.size = new;
+        let new_logical = self.logical_size();
+        let factor = if old_logical.x > 0. && old_logical.y > 0. {
+            let x = new_logical.x / old_logical.x;
+            let y = new_logical.y / old_logical.y;
+            match self.resize_policy {
+                ResizePolicy::KeepZoom | ResizePolicy::KeepTopLeft => 1.,
+                ResizePolicy::KeepWidth => x,
+                ResizePolicy::KeepHeight => y,
+                ResizePolicy::Fit => x.min(y),
+            }
+        } else {
+            1.
+        };
+
+        self.scale = self.clamp_zoom([self.scale.x * factor, self.scale.y * factor]);
+        self.place_world_at_screen(world_pin, new_pin);
+        self.confine();
+    }
+
     pub fn set_position<P>(&mut self, point: P)
     where
         P: Into<Point2<f32>>,
@@ -100,15 +305,141 @@ impl Camera {
         let point: Point2<f32> = point.into();
         self.position.x = point.x;
         self.position.y = point.y;
+        self.confine();
     }
 
     pub fn set_offset<P>(&mut self, point: P)
     where
         P: Into<Point2<f32>>,
     {
-        let point: Point2<f32> = point.into();
-        self.offset.x = point.x * self.scale.x;
-        self.offset.y = point.y * self.scale.y;
+        self.offset = point.into();
+    }
+
+    pub fn set_anchor(&mut self, anchor: Anchor) {
+        self.anchor = anchor;
+        self.confine();
+    }
+
+    pub fn screen_anchor(&self) -> Point2<f32> {
+        let anchor = self.anchor.resolve(self.logical_size());
+        let screen_scale = self.screen_scale();
+        let content = self.content_rect();
+        Point2 {
+            x: content.x + (anchor.x + self.offset.x) * screen_scale.x,
+            y: content.y + (anchor.y + self.offset.y) * screen_scale.y,
+        }
+    }
+
+    pub fn set_virtual_resolution(&mut self, virtual_resolution: Option<VirtualResolution>) {
+        self.virtual_resolution = virtual_resolution;
+        self.confine();
+    }
+
+    pub fn logical_size(&self) -> Vector2<f32> {
+        match self.virtual_resolution {
+            Some(virtual_resolution) => {
+                virtual_resolution.logical_size(self.viewport.size, self.pixel_perfect)
+            }
+            None => self.viewport.size,
+        }
+    }
+
+    pub fn screen_scale(&self) -> Vector2<f32> {
+        match self.virtual_resolution {
+            Some(virtual_resolution) => {
+                virtual_resolution.screen_scale(self.viewport.size, self.pixel_perfect)
+            }
+            None => Vector2 { x: 1., y: 1. },
+        }
+    }
+
+    pub fn effective_zoom(&self) -> Vector2<f32> {
+        let screen_scale = self.screen_scale();
+        let scale = self.render_scale();
+        Vector2 {
+            x: scale.x * screen_scale.x,
+            y: scale.y * screen_scale.y,
+        }
+    }
+
+    // Zoom actually used for rendering; pixel-perfect mode rounds it so that one
+    // world unit covers a whole number of virtual pixels, on top of the integer
+    // `screen_scale`.
+    fn render_scale(&self) -> Vector2<f32> {
+        if !self.pixel_perfect {
+            return self.scale;
+        }
+        Vector2 {
+            x: self.scale.x.round().max(1.),
+            y: self.scale.y.round().max(1.),
+        }
+    }
+
+    // Zoom limits, plus a floor of 1 in pixel-perfect mode where smaller zooms
+    // cannot be rendered.
+    pub(crate) fn clamp_zoom<V>(&self, scale: V) -> Vector2<f32>
+    where
+        V: Into<Vector2<f32>>,
+    {
+        let scale = self.zoom_limits.clamp(scale);
+        if !self.pixel_perfect {
+            return scale;
+        }
+        Vector2 {
+            x: scale.x.max(1.),
+            y: scale.y.max(1.),
+        }
+    }
+
+    pub fn set_pixel_perfect(&mut self, pixel_perfect: bool) {
+        self.pixel_perfect = pixel_perfect;
+        self.set_zoom(self.scale);
+    }
+
+    pub fn content_rect(&self) -> Rect {
+        let logical_size = self.logical_size();
+        let screen_scale = self.screen_scale();
+        let w = logical_size.x * screen_scale.x;
+        let h = logical_size.y * screen_scale.y;
+        Rect::new(
+            self.viewport.origin.x + (self.viewport.size.x - w) / 2.,
+            self.viewport.origin.y + (self.viewport.size.y - h) / 2.,
+            w,
+            h,
+        )
+    }
+
+    pub fn visible_screen_rect(&self) -> Rect {
+        let content = self.content_rect();
+        let left = content.left().max(self.viewport.origin.x);
+        let top = content.top().max(self.viewport.origin.y);
+        let right = content
+            .right()
+            .min(self.viewport.origin.x + self.viewport.size.x);
+        let bottom = content
+            .bottom()
+            .min(self.viewport.origin.y + self.viewport.size.y);
+        Rect::new(left, top, (right - left).max(0.), (bottom - top).max(0.))
+    }
+
+    pub fn letterbox_bars(&self) -> Vec<Rect> {
+        let visible = self.visible_screen_rect();
+        let Point2 { x, y } = self.viewport.origin;
+        let Vector2 { x: w, y: h } = self.viewport.size;
+        [
+            Rect::new(x, y, w, visible.top() - y),
+            Rect::new(x, visible.bottom(), w, y + h - visible.bottom()),
+            Rect::new(x, visible.top(), visible.left() - x, visible.h),
+            Rect::new(
+                visible.right(),
+                visible.top(),
+                x + w - visible.right(),
+                visible.h,
+            ),
+        ]
+        .into_iter()
+        .filter(|bar| bar.w > 0.01 && bar.h > 0.01)
+        .collect()
     }
 
     pub fn move_by_world_coords<P>(&mut self, delta: P)
@@ -118,6 +449,7 @@ impl Camera {
         let delta: Point2<f32> = delta.into();
         self.position.x -= delta.x;
         self.position.y -= delta.y;
+        self.confine();
     }
 
     pub fn move_by_screen_coords<P>(&mut self, delta: P)
@@ -125,8 +457,13 @@ impl Camera {
         P: Into<Point2<f32>>,
     {
         let delta: Point2<f32> = delta.into();
-        self.position.x -= delta.x / self.scale.x;
-        self.position.y -= delta.y / self.scale.y;
+        let world_delta = self
+            .to_matrix()
+            .inverse()
+            .transform_vector3(Vec3::new(delta.x, delta.y, 0.));
+        self.position.x -= world_delta.x;
+        self.position.y -= world_delta.y;
+        self.confine();
     }
 
     pub fn get_zoom(&self) -> Vector2<f32> {
@@ -137,7 +474,13 @@ impl Camera {
     where
         V: Into<Vector2<f32>>,
     {
-        self.scale = scale.into();
+        self.scale = self.clamp_zoom(scale);
+        self.confine();
+    }
+
+    pub fn set_zoom_limits(&mut self, limits: ZoomLimits) {
+        self.zoom_limits = limits;
+        self.set_zoom(self.scale);
     }
 
     pub fn zoom<V>(&mut self, factor: V)
@@ -145,25 +488,14 @@ impl Camera {
         V: Into<Vector2<f32>>,
     {
         let factor: Vector2<f32> = factor.into();
-        self.scale.x *= factor.x;
-        self.scale.y *= factor.y;
+        self.set_zoom([self.scale.x * factor.x, self.scale.y * factor.y]);
     }
 
-    pub fn zoom_center<V>(&mut self, ctx: &Context, factor: V)
+    pub fn zoom_center<V>(&mut self, factor: V)
     where
         V: Into<Vector2<f32>>,
     {
-        let factor: Vector2<f32> = factor.into();
-        let screen_rect = ctx.gfx.drawable_size();
-        let screen_center = Point2 {
-            x: screen_rect.0 / 2.0,
-            y: screen_rect.1 / 2.0,
-        };
-        let world_center = self.screen_to_world_coords(screen_center);
-        self.position.x = world_center.x - (world_center.x - self.position.x) / factor.x;
-        self.position.y = world_center.y - (world_center.y - self.position.y) / factor.y;
-        self.scale.x *= factor.x;
-        self.scale.y *= factor.y;
+        self.zoom_at_screen_coords(self.viewport.center(), factor);
     }
 
     pub fn zoom_at_screen_coords<P, V>(&mut self, point: P, factor: V)
@@ -171,21 +503,88 @@ impl Camera {
         P: Into<Point2<f32>>,
         V: Into<Vector2<f32>>,
     {
-        let point: Point2<f32> = point.into();
         let factor: Vector2<f32> = factor.into();
-        let world_center = self.screen_to_world_coords(point);
-        self.position.x = world_center.x - (world_center.x - self.position.x) / factor.x;
-        self.position.y = world_center.y - (world_center.y - self.position.y) / factor.y;
-        self.scale.x *= factor.x;
-        self.scale.y *= factor.y;
+        let scale = [self.scale.x * factor.x, self.scale.y * factor.y];
+        self.zoom_around(point, scale);
+    }
+
+    pub fn zoom_to_next_level<P>(&mut self, point: P, levels: &[f32])
+    where
+        P: Into<Point2<f32>>,
+    {
+        self.zoom_to_level(point, levels, zoom::next_level);
+    }
+
+    pub fn zoom_to_previous_level<P>(&mut self, point: P, levels: &[f32])
+    where
+        P: Into<Point2<f32>>,
+    {
+        self.zoom_to_level(point, levels, zoom::previous_level);
+    }
+
+    // Each axis snaps on its own, so a non-uniform zoom lands on a level on both.
+    fn zoom_to_level<P, F>(&mut self, point: P, levels: &[f32], level: F)
+    where
+        P: Into<Point2<f32>>,
+        F: Fn(f32, &[f32]) -> Option<f32>,
+    {
+        let x = level(self.scale.x, levels);
+        let y = level(self.scale.y, levels);
+        if x.is_some() || y.is_some() {
+            let scale = [x.unwrap_or(self.scale.x), y.unwrap_or(self.scale.y)];
+            self.zoom_around(point, scale);
+        }
+    }
+
+    fn zoom_around<P, V>(&mut self, point: P, scale: V)
+    where
+        P: Into<Point2<f32>>,
+        V: Into<Vector2<f32>>,
+    {
+        let scale = self.clamp_zoom(scale);
+        self.keep_screen_point(point.into(), |camera| camera.scale = scale);
+        self.confine();
+    }
+
+    fn keep_screen_point<F>(&mut self, point: Point2<f32>, change: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let world_anchor = self.screen_to_world_coords(point);
+        change(self);
+        self.place_world_at_screen(world_anchor, point);
+    }
+
+    pub(crate) fn place_world_at_screen(&mut self, world: Point2<f32>, screen: Point2<f32>) {
+        let current = self.screen_to_world_coords(screen);
+        self.position.x += world.x - current.x;
+        self.position.y += world.y - current.y;
     }
 
     pub fn rotate(&mut self, angle: f32) {
         self.rotation += angle;
+        self.confine();
     }
 
     pub fn set_rotation(&mut self, angle: f32) {
         self.rotation = angle;
+        self.confine();
+    }
+
+    pub fn rotate_at_screen_coords<P>(&mut self, point: P, angle: f32)
+    where
+        P: Into<Point2<f32>>,
+    {
+        self.keep_screen_point(point.into(), |camera| camera.rotation += angle);
+        self.confine();
+    }
+
+    pub fn rotate_at_world_coords<P>(&mut self, point: P, angle: f32)
+    where
+        P: Into<Point2<f32>>,
+    {
+        let point = self.world_to_screen_coords(point);
+        self.rotate_at_screen_coords(point, angle);
     }
 }
 
@@ -194,3 +593,9 @@ impl From<Camera> for DrawParam {
         DrawParam::default().transform(value.to_matrix())
     }
 }
+
+impl From<&Camera> for DrawParam {
+    fn from(value: &Camera) -> Self {
+        DrawParam::default().transform(value.to_matrix())
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use ggez::{glam::Vec2, mint::Point2};
+
+use super::{camera::Camera, geometry};
+
+const TOLERANCE: f32 = 1e-3;
+const MAX_ITERATIONS: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfineMode {
+    Center,
+    View,
+}
+
+// The polygon is shared, so cloning a confiner or the `Camera` holding it does
+// not copy the vertices.
+#[derive(Debug, Clone)]
+pub struct PolygonConfiner {
+    pub mode: ConfineMode,
+    polygon: Arc<[Vec2]>,
+}
+
+impl PolygonConfiner {
+    pub fn new<I, P>(points: I, mode: ConfineMode) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Point2<f32>>,
+    {
+        let mut confiner = PolygonConfiner {
+            mode,
+            polygon: Arc::from([]),
+        };
+        confiner.set_polygon(points);
+        confiner
+    }
+
+    pub fn set_polygon<I, P>(&mut self, points: I)
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Point2<f32>>,
+    {
+        self.polygon = points
+            .into_iter()
+            .map(|point| Vec2::from(point.into()))
+            .collect();
+    }
+
+    pub fn polygon(&self) -> Vec<Point2<f32>> {
+        self.polygon.iter().map(|point| (*point).into()).collect()
+    }
+
+    pub fn contains<P>(&self, point: P) -> bool
+    where
+        P: Into<Point2<f32>>,
+    {
+        self.contains_point(Vec2::from(point.into()))
+    }
+
+    pub fn closest_point<P>(&self, point: P) -> Point2<f32>
+    where
+        P: Into<Point2<f32>>,
+    {
+        let point = Vec2::from(point.into());
+        if self.contains_point(point) {
+            return point.into();
+        }
+        geometry::closest_point_on_polygon(point, &self.polygon).into()
+    }
+
+    pub fn confine(&self, camera: &mut Camera) {
+        if self.polygon.len() < 3 {
+            return;
+        }
+        match self.mode {
+            ConfineMode::Center => camera.position = self.closest_point(camera.position),
+            ConfineMode::View => self.confine_view(camera),
+        }
+    }
+
+    // Pushes the view quad out of the nearest violation until it fits. Shapes too
+    // narrow for the view fall back to confining the centre only.
+    fn confine_view(&self, camera: &mut Camera) {
+        let start = Vec2::from(camera.position);
+        let relative = camera
+            .visible_corners()
+            .map(|corner| Vec2::from(corner) - start);
+
+        let mut position = start;
+        for _ in 0..MAX_ITERATIONS {
+            match self.deepest_violation(&relative.map(|corner| corner + position)) {
+                Some(push) => position += push,
+                None => break,
+            }
+        }
+
+        if self.is_valid(&relative.map(|corner| corner + position)) {
+            camera.position = position.into();
+        } else {
+            camera.position = self.closest_point(start);
+        }
+    }
+
+    fn deepest_violation(&self, quad: &[Vec2; 4]) -> Option<Vec2> {
+        let corner_pushes = quad
+            .iter()
+            .filter(|corner| !self.contains_point(**corner))
+            .map(|corner| geometry::closest_point_on_polygon(*corner, &self.polygon) - *corner);
+        let vertex_pushes = self
+            .polygon
+            .iter()
+            .filter_map(|vertex| penetration(*vertex, quad));
+
+        corner_pushes
+            .chain(vertex_pushes)
+            .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+    }
+
+    fn is_valid(&self, quad: &[Vec2; 4]) -> bool {
+        let corners_inside = quad.iter().all(|corner| self.contains_point(*corner));
+        let polygon = &self.polygon;
+        let no_intrusion = polygon
+            .iter()
+            .all(|vertex| penetration(*vertex, quad).is_none());
+        let no_crossing = (0..4).all(|i| {
+            (0..polygon.len()).all(|j| {
+                !geometry::segments_cross(
+                    quad[i],
+                    quad[(i + 1) % 4],
+                    polygon[j],
+                    polygon[(j + 1) % polygon.len()],
+                )
+            })
+        });
+        corners_inside && no_intrusion && no_crossing
+    }
+
+    fn contains_point(&self, point: Vec2) -> bool {
+        geometry::point_in_polygon(point, &self.polygon)
+            || geometry::closest_point_on_polygon(point, &self.polygon).distance(point) < TOLERANCE
+    }
+}
+
+// Translation that moves `quad` off a polygon vertex lying strictly inside it.
+fn penetration(vertex: Vec2, quad: &[Vec2; 4]) -> Option<Vec2> {
+    let centroid = quad.iter().copied().sum::<Vec2>() / 4.;
+    let mut shallowest: Option<(f32, Vec2)> = None;
+    for i in 0..4 {
+        let a = quad[i];
+        let b = quad[(i + 1) % 4];
+        let mut normal = (b - a).perp().normalize_or_zero();
+        if normal.dot(a - centroid) < 0. {
+            normal = -normal;
+        }
+        let depth = (a - vertex).dot(normal);
+        if depth <= TOLERANCE {
+            return None;
+        }
+        if shallowest.is_none_or(|(shallowest, _)| depth < shallowest) {
+            shallowest = Some((depth, normal));
+        }
+    }
+    shallowest.map(|(depth, normal)| -normal * depth)
+}
@@ -0,0 +1,107 @@
+use std::f32::consts::PI;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+const BACK: f32 = 1.70158;
+const BACK_IN_OUT: f32 = BACK * 1.525;
+
+impl Easing {
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1. - (1. - t) * (1. - t),
+            Easing::QuadInOut => in_out(t, |t| t * t),
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1. - (1. - t).powi(3),
+            Easing::CubicInOut => in_out(t, |t| t * t * t),
+            Easing::ExpoIn => expo_in(t),
+            Easing::ExpoOut => 1. - expo_in(1. - t),
+            Easing::ExpoInOut => in_out(t, expo_in),
+            Easing::BackIn => back_in(t, BACK),
+            Easing::BackOut => 1. - back_in(1. - t, BACK),
+            Easing::BackInOut => in_out(t, |t| back_in(t, BACK_IN_OUT)),
+            Easing::ElasticIn => elastic_in(t),
+            Easing::ElasticOut => 1. - elastic_in(1. - t),
+            Easing::ElasticInOut => in_out(t, elastic_in),
+            Easing::BounceIn => 1. - bounce_out(1. - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => in_out(t, |t| 1. - bounce_out(1. - t)),
+        }
+    }
+}
+
+// Builds an in-out curve from an ease-in curve by mirroring its second half.
+fn in_out<F>(t: f32, ease_in: F) -> f32
+where
+    F: Fn(f32) -> f32,
+{
+    if t < 0.5 {
+        ease_in(t * 2.) / 2.
+    } else {
+        1. - ease_in((1. - t) * 2.) / 2.
+    }
+}
+
+fn expo_in(t: f32) -> f32 {
+    if t <= 0. {
+        0.
+    } else {
+        2f32.powf(10. * t - 10.)
+    }
+}
+
+fn back_in(t: f32, overshoot: f32) -> f32 {
+    t * t * ((overshoot + 1.) * t - overshoot)
+}
+
+fn elastic_in(t: f32) -> f32 {
+    if t <= 0. || t >= 1. {
+        return t;
+    }
+    -(2f32.powf(10. * t - 10.)) * ((t * 10. - 10.75) * (2. * PI / 3.)).sin()
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1. / D {
+        N * t * t
+    } else if t < 2. / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
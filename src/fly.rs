@@ -0,0 +1,142 @@
+use ggez::mint::Point2;
+
+use super::camera::Camera;
+
+// Smooth and efficient zooming and panning, J. J. van Wijk and W. A. A. Nuij.
+// The path is computed between the view centres, with the view width `w`
+// standing in for zoom.
+#[derive(Debug, Clone)]
+pub struct FlyTo {
+    from: Camera,
+    to: Camera,
+    rho: f32,
+    start: Point2<f32>,
+    end: Point2<f32>,
+    distance: f32,
+    w0: f32,
+    w1: f32,
+    r0: f32,
+    length: f32,
+}
+
+impl FlyTo {
+    pub const DEFAULT_RHO: f32 = std::f32::consts::SQRT_2;
+
+    pub fn new(from: &Camera, to: &Camera, rho: f32) -> Self {
+        let start = view_center(from);
+        let end = view_center(to);
+        let distance = (end.x - start.x).hypot(end.y - start.y);
+        let w0 = view_width(from);
+        let w1 = view_width(to);
+        let rho2 = rho * rho;
+
+        let (r0, length) = if distance < 1e-6 {
+            (0., (w1 / w0).ln().abs() / rho)
+        } else {
+            let b = |w: f32, sign: f32| {
+                (w1 * w1 - w0 * w0 + sign * rho2 * rho2 * distance * distance)
+                    / (2. * w * rho2 * distance)
+            };
+            let r = |b: f32| ((b * b + 1.).sqrt() - b).ln();
+            let r0 = r(b(w0, 1.));
+            let r1 = r(b(w1, -1.));
+            (r0, (r1 - r0) / rho)
+        };
+
+        FlyTo {
+            from: from.clone(),
+            to: to.clone(),
+            rho,
+            start,
+            end,
+            distance,
+            w0,
+            w1,
+            r0,
+            length,
+        }
+    }
+
+    pub fn path_length(&self) -> f32 {
+        self.length
+    }
+
+    pub fn duration(&self, speed: f32) -> f32 {
+        self.length / speed
+    }
+
+    pub fn sample(&self, t: f32) -> Camera {
+        if t <= 0. {
+            return self.from.clone();
+        }
+        if t >= 1. {
+            return self.to.clone();
+        }
+        if self.length <= f32::EPSILON {
+            return Camera::lerp(&self.from, &self.to, t);
+        }
+
+        let s = t * self.length;
+        let (u, w) = if self.distance < 1e-6 {
+            let direction = if self.w1 < self.w0 { -1. } else { 1. };
+            (0., self.w0 * (direction * self.rho * s).exp())
+        } else {
+            let rho2 = self.rho * self.rho;
+            let u = self.w0 / rho2
+                * (self.r0.cosh() * (self.rho * s + self.r0).tanh() - self.r0.sinh());
+            let w = self.w0 * self.r0.cosh() / (self.rho * s + self.r0).cosh();
+            (u / self.distance, w)
+        };
+
+        let mut camera = Camera::lerp(&self.from, &self.to, t);
+        let ratio = camera.scale.y / camera.scale.x;
+        // Zoom limits flatten the arc rather than being exceeded.
+        let zoom = visible_width_px(&camera) / w;
+        camera.scale = camera.clamp_zoom([zoom, zoom * ratio]);
+
+        let center = Point2 {
+            x: self.start.x + (self.end.x - self.start.x) * u,
+            y: self.start.y + (self.end.y - self.start.y) * u,
+        };
+        let visible = camera.visible_screen_rect();
+        camera.place_world_at_screen(
+            center,
+            Point2 {
+                x: visible.x + visible.w / 2.,
+                y: visible.y + visible.h / 2.,
+            },
+        );
+        camera
+    }
+
+    pub fn at_time(&self, time: f32, speed: f32) -> Camera {
+        let duration = self.duration(speed);
+        if duration <= 0. {
+            return self.sample(1.);
+        }
+        self.sample(time / duration)
+    }
+
+    pub fn states(&self, count: usize) -> Vec<Camera> {
+        match count {
+            0 => Vec::new(),
+            1 => vec![self.sample(1.)],
+            _ => (0..count)
+                .map(|i| self.sample(i as f32 / (count - 1) as f32))
+                .collect(),
+        }
+    }
+}
+
+fn view_center(camera: &Camera) -> Point2<f32> {
+    let visible = camera.visible_screen_rect();
+    camera.screen_to_world_coords([visible.x + visible.w / 2., visible.y + visible.h / 2.])
+}
+
+fn visible_width_px(camera: &Camera) -> f32 {
+    (camera.visible_screen_rect().w / camera.screen_scale().x).max(1.)
+}
+
+fn view_width(camera: &Camera) -> f32 {
+    visible_width_px(camera) / camera.scale.x
+}
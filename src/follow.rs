@@ -0,0 +1,210 @@
+use ggez::{
+    graphics::Rect,
+    mint::{Point2, Vector2},
+};
+
+use super::{camera::Camera, geometry};
+
+#[derive(Debug, Clone, Copy)]
+pub struct FollowTarget {
+    pub position: Point2<f32>,
+    pub zoom: Option<Vector2<f32>>,
+    pub rotation: Option<f32>,
+    pub velocity: Option<Vector2<f32>>,
+}
+
+impl FollowTarget {
+    pub fn new<P>(position: P) -> Self
+    where
+        P: Into<Point2<f32>>,
+    {
+        FollowTarget {
+            position: position.into(),
+            zoom: None,
+            rotation: None,
+            velocity: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FollowZones {
+    pub deadzone: Rect,
+    pub hard_limit: Rect,
+}
+
+impl FollowZones {
+    pub fn new(deadzone: Rect, hard_limit: Rect) -> Self {
+        FollowZones {
+            deadzone,
+            hard_limit,
+        }
+    }
+
+    pub fn screen_rects(&self, camera: &Camera) -> [Rect; 2] {
+        let visible = camera.visible_screen_rect();
+        [self.deadzone, self.hard_limit].map(|zone| {
+            Rect::new(
+                visible.x + zone.x * visible.w,
+                visible.y + zone.y * visible.h,
+                zone.w * visible.w,
+                zone.h * visible.h,
+            )
+        })
+    }
+
+    // Moves the camera by the fraction `t` of what it takes to bring `focus` back
+    // into the deadzone, then makes sure `target` never leaves the hard limit.
+    fn follow(&self, camera: &mut Camera, focus: Point2<f32>, target: Point2<f32>, t: f32) {
+        let [deadzone, hard_limit] = self.screen_rects(camera);
+        let excess = outside(deadzone, camera.world_to_screen_coords(focus));
+        camera.move_by_screen_coords([-excess.x * t, -excess.y * t]);
+
+        let excess = outside(hard_limit, camera.world_to_screen_coords(target));
+        camera.move_by_screen_coords([-excess.x, -excess.y]);
+    }
+}
+
+fn outside(rect: Rect, point: Point2<f32>) -> Vector2<f32> {
+    Vector2 {
+        x: point.x - point.x.clamp(rect.left(), rect.right()),
+        y: point.y - point.y.clamp(rect.top(), rect.bottom()),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LookAhead {
+    pub time: f32,
+    pub max_distance: f32,
+    pub half_life: f32,
+    pub x: bool,
+    pub y: bool,
+    lead: Vector2<f32>,
+    last_position: Option<Point2<f32>>,
+}
+
+impl LookAhead {
+    pub fn new(time: f32, max_distance: f32) -> Self {
+        LookAhead {
+            time,
+            max_distance,
+            half_life: 0.25,
+            x: true,
+            y: true,
+            lead: Vector2 { x: 0., y: 0. },
+            last_position: None,
+        }
+    }
+
+    pub fn lead(&self) -> Vector2<f32> {
+        self.lead
+    }
+
+    pub fn reset(&mut self) {
+        self.lead = Vector2 { x: 0., y: 0. };
+        self.last_position = None;
+    }
+
+    fn focus(&mut self, target: &FollowTarget, dt: f32) -> Point2<f32> {
+        let velocity = match (target.velocity, self.last_position) {
+            (Some(velocity), _) => velocity,
+            (None, Some(last)) if dt > 0. => Vector2 {
+                x: (target.position.x - last.x) / dt,
+                y: (target.position.y - last.y) / dt,
+            },
+            _ => Vector2 { x: 0., y: 0. },
+        };
+        self.last_position = Some(target.position);
+
+        let mut desired = Vector2 {
+            x: if self.x { velocity.x * self.time } else { 0. },
+            y: if self.y { velocity.y * self.time } else { 0. },
+        };
+        let length = desired.x.hypot(desired.y);
+        if length > self.max_distance {
+            desired.x *= self.max_distance / length;
+            desired.y *= self.max_distance / length;
+        }
+
+        let t = damping(self.half_life, dt);
+        self.lead.x += (desired.x - self.lead.x) * t;
+        self.lead.y += (desired.y - self.lead.y) * t;
+
+        Point2 {
+            x: target.position.x + self.lead.x,
+            y: target.position.y + self.lead.y,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CameraFollow {
+    pub position_half_life: f32,
+    pub zoom_half_life: f32,
+    pub rotation_half_life: f32,
+    pub zones: Option<FollowZones>,
+    pub look_ahead: Option<LookAhead>,
+}
+
+impl Default for CameraFollow {
+    fn default() -> Self {
+        CameraFollow {
+            position_half_life: 0.1,
+            zoom_half_life: 0.2,
+            rotation_half_life: 0.2,
+            zones: None,
+            look_ahead: None,
+        }
+    }
+}
+
+impl CameraFollow {
+    pub fn new(half_life: f32) -> Self {
+        CameraFollow {
+            position_half_life: half_life,
+            zoom_half_life: half_life,
+            rotation_half_life: half_life,
+            zones: None,
+            look_ahead: None,
+        }
+    }
+
+    pub fn update(&mut self, camera: &mut Camera, target: FollowTarget, dt: f32) {
+        let focus = match self.look_ahead.as_mut() {
+            Some(look_ahead) => look_ahead.focus(&target, dt),
+            None => target.position,
+        };
+
+        let t = damping(self.position_half_life, dt);
+        match self.zones {
+            Some(zones) => zones.follow(camera, focus, target.position, t),
+            None => camera.set_position([
+                camera.position.x + (focus.x - camera.position.x) * t,
+                camera.position.y + (focus.y - camera.position.y) * t,
+            ]),
+        }
+
+        if let Some(zoom) = target.zoom {
+            let t = damping(self.zoom_half_life, dt);
+            camera.set_zoom([
+                camera.scale.x * (zoom.x / camera.scale.x).powf(t),
+                camera.scale.y * (zoom.y / camera.scale.y).powf(t),
+            ]);
+        }
+
+        if let Some(rotation) = target.rotation {
+            let t = damping(self.rotation_half_life, dt);
+            let delta = geometry::shortest_angle(camera.rotation, rotation);
+            camera.set_rotation(camera.rotation + delta * t);
+        }
+    }
+}
+
+// Fraction of the remaining distance covered in `dt`, so that half of it is
+// left after every `half_life` seconds regardless of how `dt` is sliced.
+pub fn damping(half_life: f32, dt: f32) -> f32 {
+    if half_life <= 0. {
+        return 1.;
+    }
+    1. - 0.5f32.powf(dt / half_life)
+}
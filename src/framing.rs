@@ -0,0 +1,189 @@
+use ggez::{graphics::Rect, mint::Point2};
+
+use super::camera::Camera;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FitMode {
+    #[default]
+    Contain,
+    Cover,
+    FitWidth,
+    FitHeight,
+    Stretch,
+}
+
+impl Camera {
+    pub fn frame_rect(&mut self, rect: Rect, padding: f32, fit_mode: FitMode) {
+        let visible = self.visible_screen_rect();
+        let screen_scale = self.screen_scale();
+        let available_w = (visible.w / screen_scale.x - padding * 2.).max(f32::EPSILON);
+        let available_h = (visible.h / screen_scale.y - padding * 2.).max(f32::EPSILON);
+
+        // Size of the rect on screen at zoom 1 under the current rotation.
+        let (sinr, cosr) = self.rotation.sin_cos();
+        let (sinr, cosr) = (sinr.abs(), cosr.abs());
+        // A rect with no width or height still gets a finite zoom on that axis.
+        let extent_w = (cosr * rect.w + sinr * rect.h).max(f32::EPSILON);
+        let extent_h = (sinr * rect.w + cosr * rect.h).max(f32::EPSILON);
+
+        let fit_w = available_w / extent_w;
+        let fit_h = available_h / extent_h;
+        let scale = match fit_mode {
+            FitMode::Contain => [fit_w.min(fit_h); 2],
+            FitMode::Cover => [fit_w.max(fit_h); 2],
+            FitMode::FitWidth => [fit_w; 2],
+            FitMode::FitHeight => [fit_h; 2],
+            FitMode::Stretch => stretch_scale(rect, cosr, sinr, available_w, available_h)
+                .unwrap_or([fit_w.min(fit_h); 2]),
+        };
+
+        self.scale = self.clamp_zoom(scale);
+        let center = Point2 {
+            x: rect.x + rect.w / 2.,
+            y: rect.y + rect.h / 2.,
+        };
+        let screen_center = Point2 {
+            x: visible.x + visible.w / 2.,
+            y: visible.y + visible.h / 2.,
+        };
+        self.place_world_at_screen(center, screen_center);
+        self.confine();
+    }
+
+    pub fn frame_rect_upright(&mut self, rect: Rect, padding: f32, fit_mode: FitMode) {
+        self.rotation = 0.;
+        self.frame_rect(rect, padding, fit_mode);
+    }
+}
+
+// Per-axis zoom so the rotated rect fills the available area exactly.
+fn stretch_scale(rect: Rect, cosr: f32, sinr: f32, w: f32, h: f32) -> Option<[f32; 2]> {
+    let det = rect.w * rect.h * (cosr * cosr - sinr * sinr);
+    if det.abs() < f32::EPSILON {
+        return None;
+    }
+    let x = (w * cosr * rect.h - h * sinr * rect.h) / det;
+    let y = (h * cosr * rect.w - w * sinr * rect.w) / det;
+    (x > 0. && y > 0.).then_some([x, y])
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GroupTarget {
+    pub position: Point2<f32>,
+    pub radius: f32,
+    pub weight: f32,
+}
+
+impl GroupTarget {
+    pub fn new<P>(position: P, radius: f32, weight: f32) -> Self
+    where
+        P: Into<Point2<f32>>,
+    {
+        GroupTarget {
+            position: position.into(),
+            radius,
+            weight,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupOverflow {
+    #[default]
+    Clamp,
+    PrioritizeWeight,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GroupFraming {
+    pub min_zoom: f32,
+    pub max_zoom: f32,
+    pub padding: f32,
+    pub overflow: GroupOverflow,
+}
+
+impl Default for GroupFraming {
+    fn default() -> Self {
+        GroupFraming {
+            min_zoom: 0.25,
+            max_zoom: 1.,
+            padding: 0.,
+            overflow: GroupOverflow::default(),
+        }
+    }
+}
+
+impl GroupFraming {
+    pub fn new(min_zoom: f32, max_zoom: f32) -> Self {
+        GroupFraming {
+            min_zoom,
+            max_zoom,
+            ..Default::default()
+        }
+    }
+
+    pub fn frame(&self, camera: &mut Camera, targets: &[GroupTarget]) {
+        if targets.is_empty() {
+            return;
+        }
+
+        let (mut center, mut zoom) = self.fit(camera, targets);
+        if zoom < self.min_zoom && self.overflow == GroupOverflow::PrioritizeWeight {
+            let mut sorted = targets.to_vec();
+            sorted.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+            for count in (1..sorted.len()).rev() {
+                (center, zoom) = self.fit(camera, &sorted[..count]);
+                if zoom >= self.min_zoom {
+                    break;
+                }
+            }
+        }
+
+        let zoom = zoom.max(self.min_zoom).min(self.max_zoom);
+        camera.set_zoom([zoom, zoom]);
+        let visible = camera.visible_screen_rect();
+        let screen_center = Point2 {
+            x: visible.x + visible.w / 2.,
+            y: visible.y + visible.h / 2.,
+        };
+        camera.place_world_at_screen(center, screen_center);
+        camera.confine();
+    }
+
+    // Weighted centre of the targets and the zoom that keeps all of them in view
+    // around it, measured along the camera's rotated axes.
+    fn fit(&self, camera: &Camera, targets: &[GroupTarget]) -> (Point2<f32>, f32) {
+        let total_weight: f32 = targets.iter().map(|target| target.weight.max(0.)).sum();
+        let weight = |target: &GroupTarget| {
+            if total_weight > 0. {
+                target.weight.max(0.) / total_weight
+            } else {
+                1. / targets.len() as f32
+            }
+        };
+        let center = targets
+            .iter()
+            .fold(Point2 { x: 0., y: 0. }, |acc, target| Point2 {
+                x: acc.x + target.position.x * weight(target),
+                y: acc.y + target.position.y * weight(target),
+            });
+
+        let (sinr, cosr) = camera.rotation.sin_cos();
+        let (half_w, half_h) = targets.iter().fold((0f32, 0f32), |(w, h), target| {
+            let dx = target.position.x - center.x;
+            let dy = target.position.y - center.y;
+            let x = (cosr * dx - sinr * dy).abs() + target.radius;
+            let y = (sinr * dx + cosr * dy).abs() + target.radius;
+            (w.max(x), h.max(y))
+        });
+
+        let visible = camera.visible_screen_rect();
+        let screen_scale = camera.screen_scale();
+        let available_w = (visible.w / screen_scale.x / 2. - self.padding).max(f32::EPSILON);
+        let available_h = (visible.h / screen_scale.y / 2. - self.padding).max(f32::EPSILON);
+        let zoom =
+            (available_w / half_w.max(f32::EPSILON)).min(available_h / half_h.max(f32::EPSILON));
+
+        (center, zoom)
+    }
+}
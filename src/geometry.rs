@@ -0,0 +1,105 @@
+use ggez::glam::Vec2;
+
+pub(crate) fn convex_polygons_intersect(a: &[Vec2], b: &[Vec2]) -> bool {
+    !has_separating_axis(a, b) && !has_separating_axis(b, a)
+}
+
+fn has_separating_axis(a: &[Vec2], b: &[Vec2]) -> bool {
+    (0..a.len()).any(|i| {
+        let edge = a[(i + 1) % a.len()] - a[i];
+        let axis = edge.perp();
+        let (min_a, max_a) = project(a, axis);
+        let (min_b, max_b) = project(b, axis);
+        max_a < min_b || max_b < min_a
+    })
+}
+
+fn project(polygon: &[Vec2], axis: Vec2) -> (f32, f32) {
+    polygon
+        .iter()
+        .map(|point| point.dot(axis))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), d| {
+            (min.min(d), max.max(d))
+        })
+}
+
+pub(crate) fn point_in_convex(point: Vec2, polygon: &[Vec2]) -> bool {
+    let mut sign = 0.;
+    for i in 0..polygon.len() {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % polygon.len()];
+        let cross = (b - a).perp_dot(point - a);
+        if cross != 0. {
+            if sign != 0. && cross.signum() != sign {
+                return false;
+            }
+            sign = cross.signum();
+        }
+    }
+    // A zero-area polygon has no inside.
+    sign != 0.
+}
+
+pub(crate) fn closest_point_on_segment(point: Vec2, a: Vec2, b: Vec2) -> Vec2 {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared == 0. {
+        return a;
+    }
+    let t = ((point - a).dot(ab) / length_squared).clamp(0., 1.);
+    a + ab * t
+}
+
+pub(crate) fn circle_intersects_convex(center: Vec2, radius: f32, polygon: &[Vec2]) -> bool {
+    if point_in_convex(center, polygon) {
+        return true;
+    }
+    (0..polygon.len()).any(|i| {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % polygon.len()];
+        closest_point_on_segment(center, a, b).distance_squared(center) <= radius * radius
+    })
+}
+
+pub(crate) fn point_in_polygon(point: Vec2, polygon: &[Vec2]) -> bool {
+    let mut inside = false;
+    for i in 0..polygon.len() {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % polygon.len()];
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+pub(crate) fn closest_point_on_polygon(point: Vec2, polygon: &[Vec2]) -> Vec2 {
+    (0..polygon.len())
+        .map(|i| closest_point_on_segment(point, polygon[i], polygon[(i + 1) % polygon.len()]))
+        .min_by(|a, b| {
+            a.distance_squared(point)
+                .total_cmp(&b.distance_squared(point))
+        })
+        .unwrap_or(point)
+}
+
+pub(crate) fn segments_cross(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> bool {
+    let ab = b - a;
+    let cd = d - c;
+    let d1 = ab.perp_dot(c - a);
+    let d2 = ab.perp_dot(d - a);
+    let d3 = cd.perp_dot(a - c);
+    let d4 = cd.perp_dot(b - c);
+    d1 * d2 < 0. && d3 * d4 < 0.
+}
+
+pub(crate) fn shortest_angle(from: f32, to: f32) -> f32 {
+    let delta = (to - from).rem_euclid(std::f32::consts::TAU);
+    if delta > std::f32::consts::PI {
+        delta - std::f32::consts::TAU
+    } else {
+        delta
+    }
+}
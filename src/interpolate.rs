@@ -0,0 +1,59 @@
+use super::{anchor::Anchor, camera::Camera, geometry};
+
+pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+pub fn lerp_angle(a: f32, b: f32, t: f32) -> f32 {
+    a + geometry::shortest_angle(a, b) * t
+}
+
+// Interpolates zoom geometrically, so 0.1 -> 1 takes as long as 1 -> 10.
+pub fn lerp_zoom(a: f32, b: f32, t: f32) -> f32 {
+    if a > 0. && b > 0. {
+        a * (b / a).powf(t)
+    } else {
+        lerp(a, b, t)
+    }
+}
+
+impl Camera {
+    pub fn lerp(a: &Camera, b: &Camera, t: f32) -> Camera {
+        let mut camera = Self::blend_base(a, b, t);
+        camera.position.x = lerp(a.position.x, b.position.x, t);
+        camera.position.y = lerp(a.position.y, b.position.y, t);
+        camera
+    }
+
+    // Like `lerp`, but pans in step with the zoom so that the motion looks
+    // uniform on screen instead of rushing while zoomed in.
+    pub fn slerp(a: &Camera, b: &Camera, t: f32) -> Camera {
+        let mut camera = Self::blend_base(a, b, t);
+        let inverse_a = 1. / a.scale.x;
+        let inverse_b = 1. / b.scale.x;
+        let u = if (inverse_b - inverse_a).abs() > f32::EPSILON * inverse_a.abs() {
+            (1. / camera.scale.x - inverse_a) / (inverse_b - inverse_a)
+        } else {
+            t
+        };
+        camera.position.x = lerp(a.position.x, b.position.x, u);
+        camera.position.y = lerp(a.position.y, b.position.y, u);
+        camera
+    }
+
+    // Everything but the position; settings that cannot be blended come from
+    // whichever state is closer.
+    fn blend_base(a: &Camera, b: &Camera, t: f32) -> Camera {
+        let mut camera = if t < 0.5 { a.clone() } else { b.clone() };
+        camera.scale.x = lerp_zoom(a.scale.x, b.scale.x, t);
+        camera.scale.y = lerp_zoom(a.scale.y, b.scale.y, t);
+        camera.rotation = lerp_angle(a.rotation, b.rotation, t);
+        camera.offset.x = lerp(a.offset.x, b.offset.x, t);
+        camera.offset.y = lerp(a.offset.y, b.offset.y, t);
+        camera.anchor = Anchor::new(
+            lerp(a.anchor.x, b.anchor.x, t),
+            lerp(a.anchor.y, b.anchor.y, t),
+        );
+        camera
+    }
+}
@@ -1,6 +1,35 @@
+pub mod anchor;
+pub mod bounds;
 pub mod camera;
+pub mod confiner;
+pub mod easing;
+pub mod fly;
+pub mod follow;
+pub mod framing;
+mod geometry;
+pub mod interpolate;
+pub mod path;
+pub mod shake;
+pub mod spring;
+pub mod timeline;
 pub mod transform;
+pub mod tween;
+pub mod viewport;
+pub mod zoom;
 
+pub use anchor::*;
+pub use bounds::*;
 pub use camera::*;
+pub use confiner::*;
+pub use easing::*;
+pub use fly::*;
+pub use follow::*;
+pub use framing::*;
+pub use path::*;
+pub use shake::*;
+pub use spring::*;
+pub use timeline::*;
 pub use transform::*;
-
+pub use tween::*;
+pub use viewport::*;
+pub use zoom::*;
@@ -0,0 +1,278 @@
+use std::{error::Error, fmt};
+
+use ggez::{
+    glam::Vec2,
+    graphics::{Color, Mesh},
+    mint::{Point2, Vector2},
+    Context, GameResult,
+};
+
+use super::{
+    camera::Camera,
+    interpolate::{lerp, lerp_angle, lerp_zoom},
+};
+
+const SAMPLES_PER_SEGMENT: usize = 32;
+const MAX_POLYLINE_SEGMENTS: usize = 4096;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PathPoint {
+    pub position: Point2<f32>,
+    pub zoom: Option<Vector2<f32>>,
+    pub rotation: Option<f32>,
+}
+
+impl PathPoint {
+    pub fn new<P>(position: P) -> Self
+    where
+        P: Into<Point2<f32>>,
+    {
+        PathPoint {
+            position: position.into(),
+            zoom: None,
+            rotation: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplineKind {
+    CatmullRom,
+    Bezier,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PathSample {
+    pub position: Point2<f32>,
+    pub zoom: Option<Vector2<f32>>,
+    pub rotation: Option<f32>,
+}
+
+impl PathSample {
+    pub fn apply(&self, camera: &mut Camera) {
+        camera.set_position(self.position);
+        if let Some(zoom) = self.zoom {
+            camera.set_zoom(zoom);
+        }
+        if let Some(rotation) = self.rotation {
+            camera.set_rotation(rotation);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathError {
+    NoPoints,
+    // Bezier paths need `3n + 1` points; holds the number given.
+    BezierPointCount(usize),
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PathError::NoPoints => write!(f, "a camera path needs at least one point"),
+            PathError::BezierPointCount(count) => {
+                write!(f, "a Bezier camera path needs 3n + 1 points, got {count}")
+            }
+        }
+    }
+}
+
+impl Error for PathError {}
+
+// Catmull-Rom paths pass through every point. Bezier paths are given as
+// `anchor, control, control, anchor, ...` and only the anchors are passed
+// through; zoom and rotation are read from the anchors only.
+#[derive(Debug, Clone)]
+pub struct CameraPath {
+    kind: SplineKind,
+    points: Vec<PathPoint>,
+    arc_lengths: Vec<(f32, f32)>,
+}
+
+impl CameraPath {
+    pub fn new(kind: SplineKind, points: Vec<PathPoint>) -> Result<Self, PathError> {
+        if points.is_empty() {
+            return Err(PathError::NoPoints);
+        }
+        if kind == SplineKind::Bezier && !(points.len() - 1).is_multiple_of(3) {
+            return Err(PathError::BezierPointCount(points.len()));
+        }
+        let mut path = CameraPath {
+            kind,
+            points,
+            arc_lengths: Vec::new(),
+        };
+        path.build_arc_lengths();
+        Ok(path)
+    }
+
+    pub fn kind(&self) -> SplineKind {
+        self.kind
+    }
+
+    pub fn points(&self) -> &[PathPoint] {
+        &self.points
+    }
+
+    pub fn length(&self) -> f32 {
+        self.arc_lengths.last().map_or(0., |(length, _)| *length)
+    }
+
+    pub fn duration(&self, speed: f32) -> f32 {
+        self.length() / speed
+    }
+
+    pub fn sample_distance(&self, distance: f32) -> PathSample {
+        let u = self.parameter_at(distance);
+        PathSample {
+            position: self.position_at(u).into(),
+            zoom: self.keyed(u, |point| point.zoom).map(|(a, b, t)| Vector2 {
+                x: lerp_zoom(a.x, b.x, t),
+                y: lerp_zoom(a.y, b.y, t),
+            }),
+            rotation: self
+                .keyed(u, |point| point.rotation)
+                .map(|(a, b, t)| lerp_angle(a, b, t)),
+        }
+    }
+
+    pub fn sample_time(&self, time: f32, speed: f32) -> PathSample {
+        self.sample_distance(time * speed)
+    }
+
+    pub fn apply(&self, camera: &mut Camera, distance: f32) {
+        self.sample_distance(distance).apply(camera);
+    }
+
+    // Points roughly `spacing` apart; tiny spacings are capped at
+    // `MAX_POLYLINE_SEGMENTS` segments.
+    pub fn polyline(&self, spacing: f32) -> Vec<Point2<f32>> {
+        let length = self.length();
+        let count =
+            ((length / spacing.max(f32::EPSILON)).ceil() as usize).clamp(1, MAX_POLYLINE_SEGMENTS);
+        (0..=count)
+            .map(|i| {
+                self.sample_distance(length * i as f32 / count as f32)
+                    .position
+            })
+            .collect()
+    }
+
+    pub fn to_mesh(
+        &self,
+        ctx: &Context,
+        spacing: f32,
+        width: f32,
+        color: Color,
+    ) -> GameResult<Mesh> {
+        Mesh::new_line(ctx, &self.polyline(spacing), width, color)
+    }
+
+    fn segment_count(&self) -> usize {
+        match self.kind {
+            SplineKind::CatmullRom => self.points.len().saturating_sub(1),
+            SplineKind::Bezier => self.points.len().saturating_sub(1) / 3,
+        }
+    }
+
+    fn knot(&self, index: usize) -> &PathPoint {
+        match self.kind {
+            SplineKind::CatmullRom => &self.points[index],
+            SplineKind::Bezier => &self.points[index * 3],
+        }
+    }
+
+    // Position at the global spline parameter `u`, where segment `i` spans `[i, i + 1]`.
+    fn position_at(&self, u: f32) -> Vec2 {
+        let segments = self.segment_count();
+        if segments == 0 {
+            return self.points[0].position.into();
+        }
+        let index = (u.floor() as usize).min(segments - 1);
+        let t = u - index as f32;
+        let point = |i: usize| Vec2::from(self.points[i].position);
+
+        match self.kind {
+            SplineKind::CatmullRom => {
+                let p0 = point(index.saturating_sub(1));
+                let p1 = point(index);
+                let p2 = point(index + 1);
+                let p3 = point((index + 2).min(self.points.len() - 1));
+                let t2 = t * t;
+                let t3 = t2 * t;
+                ((p1 * 2.)
+                    + (p2 - p0) * t
+                    + (p0 * 2. - p1 * 5. + p2 * 4. - p3) * t2
+                    + (p1 * 3. - p0 - p2 * 3. + p3) * t3)
+                    * 0.5
+            }
+            SplineKind::Bezier => {
+                let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| point(index * 3 + i));
+                let s = 1. - t;
+                p0 * (s * s * s) + p1 * (3. * s * s * t) + p2 * (3. * s * t * t) + p3 * (t * t * t)
+            }
+        }
+    }
+
+    fn build_arc_lengths(&mut self) {
+        let segments = self.segment_count();
+        self.arc_lengths.clear();
+        self.arc_lengths.push((0., 0.));
+        let mut length = 0.;
+        let mut previous = self.position_at(0.);
+        for i in 1..=segments * SAMPLES_PER_SEGMENT {
+            let u = i as f32 / SAMPLES_PER_SEGMENT as f32;
+            let position = self.position_at(u);
+            length += position.distance(previous);
+            previous = position;
+            self.arc_lengths.push((length, u));
+        }
+    }
+
+    fn parameter_at(&self, distance: f32) -> f32 {
+        let distance = distance.clamp(0., self.length());
+        let index = self
+            .arc_lengths
+            .partition_point(|(length, _)| *length < distance);
+        if index == 0 {
+            return 0.;
+        }
+        let (length_a, u_a) = self.arc_lengths[index - 1];
+        let (length_b, u_b) = self.arc_lengths[index.min(self.arc_lengths.len() - 1)];
+        if length_b - length_a <= f32::EPSILON {
+            return u_b;
+        }
+        lerp(u_a, u_b, (distance - length_a) / (length_b - length_a))
+    }
+
+    // Surrounding keyed values of a per-knot property and the fraction between
+    // them; held flat before the first and after the last key.
+    fn keyed<T, F>(&self, u: f32, value: F) -> Option<(T, T, f32)>
+    where
+        T: Copy,
+        F: Fn(&PathPoint) -> Option<T>,
+    {
+        let keys: Vec<(f32, T)> = (0..=self.segment_count())
+            .filter_map(|i| value(self.knot(i)).map(|v| (i as f32, v)))
+            .collect();
+
+        let first = *keys.first()?;
+        let last = *keys.last()?;
+        if u <= first.0 {
+            return Some((first.1, first.1, 0.));
+        }
+        if u >= last.0 {
+            return Some((last.1, last.1, 0.));
+        }
+        keys.windows(2)
+            .find(|pair| u >= pair[0].0 && u <= pair[1].0)
+            .map(|pair| {
+                (
+                    pair[0].1,
+                    pair[1].1,
+                    (u - pair[0].0) / (pair[1].0 - pair[0].0),
+                )
+            })
+    }
+}
@@ -0,0 +1,112 @@
+use ggez::{
+    glam::{Mat4, Vec3},
+    graphics::DrawParam,
+    mint::{Point2, Vector2},
+};
+
+use super::camera::Camera;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CameraShake {
+    pub trauma: f32,
+    pub decay: f32,
+    pub exponent: f32,
+    pub max_offset: Vector2<f32>,
+    pub max_angle: f32,
+    pub frequency: f32,
+    pub seed: u32,
+    time: f32,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        CameraShake {
+            trauma: 0.,
+            decay: 1.,
+            exponent: 2.,
+            max_offset: Vector2 { x: 20., y: 20. },
+            max_angle: 0.1,
+            frequency: 15.,
+            seed: 0,
+            time: 0.,
+        }
+    }
+}
+
+impl CameraShake {
+    pub fn new(seed: u32) -> Self {
+        CameraShake {
+            seed,
+            ..Default::default()
+        }
+    }
+
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0., 1.);
+    }
+
+    // Trauma from a world-space source, fading out linearly with its distance
+    // from the centre of the view and gone beyond `radius`.
+    pub fn add_trauma_at<P>(&mut self, camera: &Camera, source: P, amount: f32, radius: f32)
+    where
+        P: Into<Point2<f32>>,
+    {
+        let source: Point2<f32> = source.into();
+        let visible = camera.visible_screen_rect();
+        let center =
+            camera.screen_to_world_coords([visible.x + visible.w / 2., visible.y + visible.h / 2.]);
+        let distance = (source.x - center.x).hypot(source.y - center.y);
+        if radius > 0. {
+            self.add_trauma(amount * (1. - distance / radius).max(0.));
+        }
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        self.time += dt;
+        self.trauma = (self.trauma - self.decay * dt).max(0.);
+    }
+
+    pub fn offset(&self) -> (Vector2<f32>, f32) {
+        let shake = self.trauma.powf(self.exponent);
+        let t = self.time * self.frequency;
+        let offset = Vector2 {
+            x: self.max_offset.x * shake * noise(self.seed, t),
+            y: self.max_offset.y * shake * noise(self.seed.wrapping_add(1), t),
+        };
+        let angle = self.max_angle * shake * noise(self.seed.wrapping_add(2), t);
+        (offset, angle)
+    }
+
+    pub fn to_matrix(&self, camera: &Camera) -> Mat4 {
+        let (offset, angle) = self.offset();
+        let visible = camera.visible_screen_rect();
+        let center = Vec3::new(visible.x + visible.w / 2., visible.y + visible.h / 2., 0.);
+        let shake = Mat4::from_translation(center + Vec3::new(offset.x, offset.y, 0.))
+            * Mat4::from_rotation_z(angle)
+            * Mat4::from_translation(-center);
+        shake * camera.to_matrix()
+    }
+
+    pub fn draw_param(&self, camera: &Camera) -> DrawParam {
+        DrawParam::default().transform(self.to_matrix(camera))
+    }
+}
+
+// Smooth 1D gradient noise in [-1, 1], deterministic for a given seed.
+fn noise(seed: u32, x: f32) -> f32 {
+    let cell = x.floor();
+    let f = x - cell;
+    let gradient = |i: f32| {
+        let mut h = (i as i32 as u32) ^ seed.wrapping_mul(0x9e37_79b9);
+        h ^= h >> 16;
+        h = h.wrapping_mul(0x7feb_352d);
+        h ^= h >> 15;
+        h = h.wrapping_mul(0x846c_a68b);
+        h ^= h >> 16;
+        h as f32 / u32::MAX as f32 * 2. - 1.
+    };
+    let a = gradient(cell) * f;
+    let b = gradient(cell + 1.) * (f - 1.);
+    let t = f * f * (3. - 2. * f);
+    ((a + (b - a) * t) * 2.).clamp(-1., 1.)
+}
@@ -0,0 +1,127 @@
+use std::f32::consts::PI;
+
+use super::{camera::Camera, geometry};
+
+#[derive(Debug, Clone, Copy)]
+pub struct SpringParams {
+    pub frequency: f32,
+    pub damping: f32,
+    pub response: f32,
+}
+
+impl Default for SpringParams {
+    fn default() -> Self {
+        SpringParams {
+            frequency: 1.5,
+            damping: 1.,
+            response: 0.,
+        }
+    }
+}
+
+impl SpringParams {
+    pub fn new(frequency: f32, damping: f32, response: f32) -> Self {
+        SpringParams {
+            frequency,
+            damping,
+            response,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Spring {
+    pub value: f32,
+    pub velocity: f32,
+    previous_target: f32,
+}
+
+impl Spring {
+    pub fn new(value: f32) -> Self {
+        Spring {
+            value,
+            velocity: 0.,
+            previous_target: value,
+        }
+    }
+
+    // Second-order system `y + k1 y' + k2 y'' = x + k3 x'`, with k2 clamped so
+    // large time steps stay stable.
+    pub fn update(&mut self, params: &SpringParams, target: f32, dt: f32) -> f32 {
+        if dt <= 0. {
+            return self.value;
+        }
+        let k1 = params.damping / (PI * params.frequency);
+        let k2 = 1. / ((2. * PI * params.frequency) * (2. * PI * params.frequency));
+        let k3 = params.response * params.damping / (2. * PI * params.frequency);
+        let k2 = k2.max(dt * dt / 2. + dt * k1 / 2.).max(dt * k1);
+
+        let target_velocity = (target - self.previous_target) / dt;
+        self.previous_target = target;
+
+        self.value += dt * self.velocity;
+        self.velocity +=
+            dt * (target + k3 * target_velocity - self.value - k1 * self.velocity) / k2;
+        self.value
+    }
+
+    pub fn is_settled(&self, target: f32, epsilon: f32) -> bool {
+        (self.value - target).abs() <= epsilon && self.velocity.abs() <= epsilon
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CameraSpring {
+    pub position_params: SpringParams,
+    pub zoom_params: SpringParams,
+    pub rotation_params: SpringParams,
+    pub position: [Spring; 2],
+    pub scale: [Spring; 2],
+    pub rotation: Spring,
+}
+
+impl CameraSpring {
+    pub fn new(camera: &Camera, params: SpringParams) -> Self {
+        CameraSpring {
+            position_params: params,
+            zoom_params: params,
+            rotation_params: params,
+            position: [
+                Spring::new(camera.position.x),
+                Spring::new(camera.position.y),
+            ],
+            scale: [Spring::new(camera.scale.x), Spring::new(camera.scale.y)],
+            rotation: Spring::new(camera.rotation),
+        }
+    }
+
+    pub fn update(&mut self, camera: &mut Camera, target: &Camera, dt: f32) {
+        camera.set_position([
+            self.position[0].update(&self.position_params, target.position.x, dt),
+            self.position[1].update(&self.position_params, target.position.y, dt),
+        ]);
+        camera.set_zoom([
+            self.scale[0].update(&self.zoom_params, target.scale.x, dt),
+            self.scale[1].update(&self.zoom_params, target.scale.y, dt),
+        ]);
+
+        let rotation = self.unwrapped_rotation(target.rotation);
+        camera.set_rotation(self.rotation.update(&self.rotation_params, rotation, dt));
+    }
+
+    pub fn is_settled(&self, target: &Camera, epsilon: f32) -> bool {
+        self.position[0].is_settled(target.position.x, epsilon)
+            && self.position[1].is_settled(target.position.y, epsilon)
+            && self.scale[0].is_settled(target.scale.x, epsilon)
+            && self.scale[1].is_settled(target.scale.y, epsilon)
+            && self
+                .rotation
+                .is_settled(self.unwrapped_rotation(target.rotation), epsilon)
+    }
+
+    // Target angle expressed next to the current one so the spring takes the
+    // shortest way round.
+    fn unwrapped_rotation(&self, rotation: f32) -> f32 {
+        self.rotation.value + geometry::shortest_angle(self.rotation.value, rotation)
+    }
+}
@@ -0,0 +1,289 @@
+use ggez::mint::{Point2, Vector2};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    camera::Camera,
+    easing::Easing,
+    interpolate::{lerp, lerp_angle, lerp_zoom},
+};
+
+// A keyframe eases in from the previous key that sets the same property,
+// using its own `easing`, unless it is a `cut`. Its values are then held for
+// `hold` seconds before easing out towards the next key.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Keyframe {
+    pub time: f32,
+    pub position: Option<Point2<f32>>,
+    pub zoom: Option<Vector2<f32>>,
+    pub rotation: Option<f32>,
+    pub easing: Easing,
+    pub hold: f32,
+    pub cut: bool,
+}
+
+impl Keyframe {
+    pub fn new(time: f32) -> Self {
+        Keyframe {
+            time,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimelineEvent {
+    pub time: f32,
+    pub name: String,
+}
+
+impl TimelineEvent {
+    pub fn new<S>(time: f32, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        TimelineEvent {
+            time,
+            name: name.into(),
+        }
+    }
+}
+
+// Loadable from RON or JSON with the `ron` and `json` features, where points
+// and zoom are written as `(x, y)` tuples or `[x, y]` arrays respectively.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "RawTimeline"))]
+pub struct Timeline {
+    keyframes: Vec<Keyframe>,
+    events: Vec<TimelineEvent>,
+}
+
+// Deserialized form of `Timeline`, sorted through `Timeline::new` wherever a
+// timeline is loaded, including inside other types.
+#[cfg(feature = "serde")]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawTimeline {
+    keyframes: Vec<Keyframe>,
+    events: Vec<TimelineEvent>,
+}
+
+#[cfg(feature = "serde")]
+impl From<RawTimeline> for Timeline {
+    fn from(raw: RawTimeline) -> Self {
+        Timeline::new(raw.keyframes, raw.events)
+    }
+}
+
+impl Timeline {
+    pub fn new(keyframes: Vec<Keyframe>, events: Vec<TimelineEvent>) -> Self {
+        let mut timeline = Timeline { keyframes, events };
+        timeline.sort();
+        timeline
+    }
+
+    #[cfg(feature = "ron")]
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json(source: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(source)
+    }
+
+    pub fn keyframes(&self) -> &[Keyframe] {
+        &self.keyframes
+    }
+
+    pub fn events(&self) -> &[TimelineEvent] {
+        &self.events
+    }
+
+    pub fn duration(&self) -> f32 {
+        let keyframes = self
+            .keyframes
+            .iter()
+            .map(|keyframe| keyframe.time + keyframe.hold.max(0.));
+        let events = self.events.iter().map(|event| event.time);
+        keyframes.chain(events).fold(0., f32::max)
+    }
+
+    // Properties no keyframe sets are taken from `base`.
+    pub fn camera_at(&self, base: &Camera, time: f32) -> Camera {
+        let mut camera = base.clone();
+        if let Some(position) = self.sample(
+            time,
+            |keyframe| keyframe.position,
+            |a, b, t| Point2 {
+                x: lerp(a.x, b.x, t),
+                y: lerp(a.y, b.y, t),
+            },
+        ) {
+            camera.set_position(position);
+        }
+        if let Some(zoom) = self.sample(
+            time,
+            |keyframe| keyframe.zoom,
+            |a, b, t| Vector2 {
+                x: lerp_zoom(a.x, b.x, t),
+                y: lerp_zoom(a.y, b.y, t),
+            },
+        ) {
+            camera.set_zoom(zoom);
+        }
+        if let Some(rotation) = self.sample(time, |keyframe| keyframe.rotation, lerp_angle) {
+            camera.set_rotation(rotation);
+        }
+        camera
+    }
+
+    fn sort(&mut self) {
+        self.keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        self.events.sort_by(|a, b| a.time.total_cmp(&b.time));
+    }
+
+    fn sample<T, F, L>(&self, time: f32, value: F, interpolate: L) -> Option<T>
+    where
+        T: Copy,
+        F: Fn(&Keyframe) -> Option<T>,
+        L: Fn(T, T, f32) -> T,
+    {
+        let mut keys = self
+            .keyframes
+            .iter()
+            .filter_map(|keyframe| value(keyframe).map(|v| (keyframe, v)));
+        let (mut previous, mut previous_value) = keys.next()?;
+        if time <= previous.time {
+            return Some(previous_value);
+        }
+
+        for (next, next_value) in keys {
+            if time < next.time {
+                let start = previous.time + previous.hold.max(0.);
+                if next.cut || time <= start || next.time <= start {
+                    return Some(previous_value);
+                }
+                let t = next.easing.apply((time - start) / (next.time - start));
+                return Some(interpolate(previous_value, next_value, t));
+            }
+            previous = next;
+            previous_value = next_value;
+        }
+        Some(previous_value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimelinePlayer {
+    timeline: Timeline,
+    pub speed: f32,
+    time: f32,
+    playing: bool,
+    // Events exactly at `time` have not fired yet.
+    pending: bool,
+}
+
+impl TimelinePlayer {
+    pub fn new(timeline: Timeline) -> Self {
+        TimelinePlayer {
+            timeline,
+            speed: 1.,
+            time: 0.,
+            playing: false,
+            pending: true,
+        }
+    }
+
+    pub fn timeline(&self) -> &Timeline {
+        &self.timeline
+    }
+
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    // Whether playback has reached the end it is heading for.
+    pub fn is_finished(&self) -> bool {
+        if self.speed < 0. {
+            self.time <= 0.
+        } else {
+            self.time >= self.timeline.duration()
+        }
+    }
+
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    // Seeking fires nothing; events at `time` fire on the next step in either
+    // direction.
+    pub fn seek(&mut self, time: f32) {
+        self.time = time.clamp(0., self.timeline.duration());
+        self.pending = true;
+    }
+
+    pub fn camera(&self, base: &Camera) -> Camera {
+        self.timeline.camera_at(base, self.time)
+    }
+
+    // Advances by `dt * speed`, poses `camera` and returns the events crossed
+    // during this step, in the order they were crossed. Playback stops when it
+    // reaches the end it is heading for; a zero speed holds the current time.
+    pub fn update(&mut self, camera: &mut Camera, dt: f32) -> Vec<&TimelineEvent> {
+        let mut crossed = 0..0;
+        let mut reverse = false;
+        if self.playing {
+            let duration = self.timeline.duration();
+            let from = self.time;
+            let to = (from + dt * self.speed).clamp(0., duration);
+            let events = &self.timeline.events;
+            let before = |time: f32| events.partition_point(|event| event.time < time);
+            let through = |time: f32| events.partition_point(|event| event.time <= time);
+
+            if to > from || (to == from && self.speed > 0.) {
+                let start = if self.pending {
+                    before(from)
+                } else {
+                    through(from)
+                };
+                crossed = start..through(to);
+                self.pending = false;
+            } else if to < from || self.speed < 0. {
+                let end = if self.pending {
+                    through(from)
+                } else {
+                    before(from)
+                };
+                crossed = before(to)..end;
+                reverse = true;
+                self.pending = false;
+            }
+            self.time = to;
+
+            if (self.speed > 0. && to >= duration) || (self.speed < 0. && to <= 0.) {
+                self.playing = false;
+            }
+        }
+        *camera = self.camera(camera);
+
+        let crossed = self.timeline.events[crossed].iter();
+        if reverse {
+            crossed.rev().collect()
+        } else {
+            crossed.collect()
+        }
+    }
+}
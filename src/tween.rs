@@ -0,0 +1,180 @@
+use std::collections::VecDeque;
+
+use ggez::mint::{Point2, Vector2};
+
+use super::{
+    camera::Camera,
+    easing::Easing,
+    interpolate::{lerp, lerp_zoom},
+};
+
+pub type TweenCallback = Box<dyn FnMut(&mut Camera)>;
+
+pub struct Tween {
+    pub position: Option<Point2<f32>>,
+    pub zoom: Option<Vector2<f32>>,
+    pub rotation: Option<f32>,
+    pub offset: Option<Point2<f32>>,
+    pub duration: f32,
+    pub easing: Easing,
+    pub on_complete: Option<TweenCallback>,
+}
+
+impl Default for Tween {
+    fn default() -> Self {
+        Tween {
+            position: None,
+            zoom: None,
+            rotation: None,
+            offset: None,
+            duration: 1.,
+            easing: Easing::default(),
+            on_complete: None,
+        }
+    }
+}
+
+impl Tween {
+    pub fn new(duration: f32, easing: Easing) -> Self {
+        Tween {
+            duration,
+            easing,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TweenId(u64);
+
+struct ActiveTween {
+    id: TweenId,
+    tween: Tween,
+    from: Camera,
+    elapsed: f32,
+}
+
+#[derive(Default)]
+pub struct CameraTweener {
+    active: Option<ActiveTween>,
+    queue: VecDeque<(TweenId, Tween)>,
+    next_id: u64,
+}
+
+impl CameraTweener {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn play(&mut self, tween: Tween) -> TweenId {
+        self.cancel();
+        self.then(tween)
+    }
+
+    pub fn then(&mut self, tween: Tween) -> TweenId {
+        let id = TweenId(self.next_id);
+        self.next_id += 1;
+        self.queue.push_back((id, tween));
+        id
+    }
+
+    pub fn cancel(&mut self) {
+        self.active = None;
+        self.queue.clear();
+    }
+
+    pub fn cancel_tween(&mut self, id: TweenId) {
+        if self.active.as_ref().is_some_and(|active| active.id == id) {
+            self.active = None;
+        }
+        self.queue.retain(|(queued, _)| *queued != id);
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active.is_some() || !self.queue.is_empty()
+    }
+
+    pub fn is_pending(&self, id: TweenId) -> bool {
+        self.active.as_ref().is_some_and(|active| active.id == id)
+            || self.queue.iter().any(|(queued, _)| *queued == id)
+    }
+
+    pub fn progress(&self) -> Option<f32> {
+        self.active
+            .as_ref()
+            .map(|active| (active.elapsed / active.tween.duration).min(1.))
+    }
+
+    // Advances the chain by `dt`, carrying leftover time into the next tween,
+    // and returns the tweens that finished during this step.
+    pub fn update(&mut self, camera: &mut Camera, dt: f32) -> Vec<TweenId> {
+        let mut finished = Vec::new();
+        let mut remaining = dt;
+
+        loop {
+            if self.active.is_none() {
+                match self.queue.pop_front() {
+                    Some((id, tween)) => {
+                        self.active = Some(ActiveTween {
+                            id,
+                            tween,
+                            from: camera.clone(),
+                            elapsed: 0.,
+                        })
+                    }
+                    None => break,
+                }
+            }
+
+            let Some(active) = self.active.as_mut() else {
+                break;
+            };
+            active.elapsed += remaining;
+            let t = if active.tween.duration > 0. {
+                active.elapsed / active.tween.duration
+            } else {
+                1.
+            };
+            apply(camera, &active.from, &active.tween, t.min(1.));
+
+            if t < 1. {
+                break;
+            }
+
+            remaining = active.elapsed - active.tween.duration.max(0.);
+            let mut active = self.active.take().unwrap();
+            if let Some(on_complete) = active.tween.on_complete.as_mut() {
+                on_complete(camera);
+            }
+            finished.push(active.id);
+        }
+
+        finished
+    }
+}
+
+fn apply(camera: &mut Camera, from: &Camera, tween: &Tween, t: f32) {
+    let t = tween.easing.apply(t);
+
+    if let Some(position) = tween.position {
+        camera.set_position([
+            lerp(from.position.x, position.x, t),
+            lerp(from.position.y, position.y, t),
+        ]);
+    }
+    if let Some(zoom) = tween.zoom {
+        camera.set_zoom([
+            lerp_zoom(from.scale.x, zoom.x, t),
+            lerp_zoom(from.scale.y, zoom.y, t),
+        ]);
+    }
+    if let Some(rotation) = tween.rotation {
+        camera.set_rotation(lerp(from.rotation, rotation, t));
+    }
+    if let Some(offset) = tween.offset {
+        camera.set_offset([
+            lerp(from.offset.x, offset.x, t),
+            lerp(from.offset.y, offset.y, t),
+        ]);
+    }
+}
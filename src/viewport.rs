@@ -0,0 +1,188 @@
+use ggez::{
+    mint::{Point2, Vector2},
+    Context,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Viewport {
+    pub size: Vector2<f32>,
+    pub origin: Point2<f32>,
+    pub scale_factor: f32,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Viewport {
+            size: Vector2 { x: 0., y: 0. },
+            origin: Point2 { x: 0., y: 0. },
+            scale_factor: 1.,
+        }
+    }
+}
+
+impl Viewport {
+    pub fn new<V>(size: V) -> Self
+    where
+        V: Into<Vector2<f32>>,
+    {
+        Viewport {
+            size: size.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_window_size<V>(size: V, scale_factor: f32) -> Self
+    where
+        V: Into<Vector2<f32>>,
+    {
+        let size: Vector2<f32> = size.into();
+        Viewport {
+            size: Vector2 {
+                x: size.x * scale_factor,
+                y: size.y * scale_factor,
+            },
+            scale_factor,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_context(ctx: &Context) -> Self {
+        let (width, height) = ctx.gfx.drawable_size();
+        Viewport {
+            size: Vector2 {
+                x: width,
+                y: height,
+            },
+            scale_factor: ctx.gfx.window().scale_factor() as f32,
+            ..Default::default()
+        }
+    }
+
+    pub fn center(&self) -> Point2<f32> {
+        Point2 {
+            x: self.origin.x + self.size.x / 2.,
+            y: self.origin.y + self.size.y / 2.,
+        }
+    }
+
+    pub fn set_size<V>(&mut self, size: V)
+    where
+        V: Into<Vector2<f32>>,
+    {
+        self.size = size.into();
+    }
+
+    pub fn set_origin<P>(&mut self, origin: P)
+    where
+        P: Into<Point2<f32>>,
+    {
+        self.origin = origin.into();
+    }
+
+    pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        self.scale_factor = scale_factor;
+    }
+
+    pub fn window_size(&self) -> Vector2<f32> {
+        Vector2 {
+            x: self.size.x / self.scale_factor,
+            y: self.size.y / self.scale_factor,
+        }
+    }
+
+    // Window coordinates are logical (DPI independent) units, drawable
+    // coordinates are physical pixels, which is what the camera matrix works in.
+    pub fn window_to_drawable<P>(&self, point: P) -> Point2<f32>
+    where
+        P: Into<Point2<f32>>,
+    {
+        let point: Point2<f32> = point.into();
+        Point2 {
+            x: point.x * self.scale_factor,
+            y: point.y * self.scale_factor,
+        }
+    }
+
+    pub fn drawable_to_window<P>(&self, point: P) -> Point2<f32>
+    where
+        P: Into<Point2<f32>>,
+    {
+        let point: Point2<f32> = point.into();
+        Point2 {
+            x: point.x / self.scale_factor,
+            y: point.y / self.scale_factor,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizePolicy {
+    #[default]
+    KeepZoom,
+    KeepWidth,
+    KeepHeight,
+    Fit,
+    KeepTopLeft,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleMode {
+    Fit,
+    Fill,
+    Stretch,
+    Expand,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct VirtualResolution {
+    pub size: Vector2<f32>,
+    pub mode: ScaleMode,
+}
+
+impl VirtualResolution {
+    pub fn new<V>(size: V, mode: ScaleMode) -> Self
+    where
+        V: Into<Vector2<f32>>,
+    {
+        VirtualResolution {
+            size: size.into(),
+            mode,
+        }
+    }
+
+    pub fn screen_scale(&self, screen_size: Vector2<f32>, integer: bool) -> Vector2<f32> {
+        let x = screen_size.x / self.size.x;
+        let y = screen_size.y / self.size.y;
+        let (x, y) = match self.mode {
+            ScaleMode::Fit | ScaleMode::Expand => (x.min(y), x.min(y)),
+            ScaleMode::Fill => (x.max(y), x.max(y)),
+            ScaleMode::Stretch => (x, y),
+        };
+        if !integer {
+            return Vector2 { x, y };
+        }
+        match self.mode {
+            ScaleMode::Fill => Vector2 {
+                x: x.ceil().max(1.),
+                y: y.ceil().max(1.),
+            },
+            _ => Vector2 {
+                x: x.floor().max(1.),
+                y: y.floor().max(1.),
+            },
+        }
+    }
+
+    pub fn logical_size(&self, screen_size: Vector2<f32>, integer: bool) -> Vector2<f32> {
+        match self.mode {
+            ScaleMode::Expand => {
+                let scale = self.screen_scale(screen_size, integer);
+                Vector2 {
+                    x: screen_size.x / scale.x,
+                    y: screen_size.y / scale.y,
+                }
+            }
+            _ => self.size,
+        }
+    }
+}
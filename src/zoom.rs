@@ -0,0 +1,61 @@
+use ggez::mint::Vector2;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ZoomLimits {
+    pub min: Vector2<f32>,
+    pub max: Vector2<f32>,
+}
+
+impl Default for ZoomLimits {
+    fn default() -> Self {
+        ZoomLimits {
+            min: Vector2 {
+                x: f32::MIN_POSITIVE,
+                y: f32::MIN_POSITIVE,
+            },
+            max: Vector2 {
+                x: f32::MAX,
+                y: f32::MAX,
+            },
+        }
+    }
+}
+
+impl ZoomLimits {
+    pub fn new<V>(min: V, max: V) -> Self
+    where
+        V: Into<Vector2<f32>>,
+    {
+        ZoomLimits {
+            min: min.into(),
+            max: max.into(),
+        }
+    }
+
+    pub fn clamp<V>(&self, scale: V) -> Vector2<f32>
+    where
+        V: Into<Vector2<f32>>,
+    {
+        let scale: Vector2<f32> = scale.into();
+        Vector2 {
+            x: scale.x.max(self.min.x).min(self.max.x),
+            y: scale.y.max(self.min.y).min(self.max.y),
+        }
+    }
+}
+
+pub(crate) fn next_level(current: f32, levels: &[f32]) -> Option<f32> {
+    levels
+        .iter()
+        .copied()
+        .filter(|level| *level > current * (1. + f32::EPSILON * 4.))
+        .min_by(f32::total_cmp)
+}
+
+pub(crate) fn previous_level(current: f32, levels: &[f32]) -> Option<f32> {
+    levels
+        .iter()
+        .copied()
+        .filter(|level| *level < current * (1. - f32::EPSILON * 4.))
+        .max_by(f32::total_cmp)
+}
@@ -0,0 +1,100 @@
+use camera2d_ggez::{Anchor, BoundsAlign, Camera, CameraBounds, Viewport, ZoomLimits};
+use ggez::graphics::Rect;
+
+const EPSILON: f32 = 1e-2;
+
+fn camera() -> Camera {
+    Camera {
+        viewport: Viewport::new([400., 300.]),
+        anchor: Anchor::CENTER,
+        ..Default::default()
+    }
+}
+
+fn assert_inside(camera: &Camera, rect: Rect) {
+    for corner in camera.visible_corners() {
+        assert!(
+            corner.x >= rect.left() - EPSILON
+                && corner.x <= rect.right() + EPSILON
+                && corner.y >= rect.top() - EPSILON
+                && corner.y <= rect.bottom() + EPSILON,
+            "corner {corner:?} outside {rect:?}"
+        );
+    }
+}
+
+#[test]
+fn rotated_view_stays_inside() {
+    let rect = Rect::new(0., 0., 2000., 1500.);
+    for rotation in [0., 0.5, 2.] {
+        let mut camera = camera();
+        camera.set_rotation(rotation);
+        camera.set_bounds(Some(CameraBounds::new(rect)));
+        for target in [[-1000., -1000.], [3000., 700.], [1000., 5000.]] {
+            camera.set_position(target);
+            assert_inside(&camera, rect);
+        }
+        camera.move_by_screen_coords([5000., 5000.]);
+        assert_inside(&camera, rect);
+    }
+}
+
+#[test]
+fn oversized_view_is_aligned() {
+    let rect = Rect::new(0., 0., 100., 100.);
+    let expected = [
+        (BoundsAlign::Center, 50., 50.),
+        (BoundsAlign::Start, 200., 150.),
+        (BoundsAlign::End, -100., -50.),
+    ];
+    for (align, x, y) in expected {
+        let mut camera = camera();
+        camera.set_position([1000., 1000.]);
+        camera.set_bounds(Some(CameraBounds {
+            align,
+            ..CameraBounds::new(rect)
+        }));
+        assert!(
+            (camera.position.x - x).abs() < EPSILON && (camera.position.y - y).abs() < EPSILON,
+            "{align:?}: {:?}",
+            camera.position
+        );
+    }
+}
+
+#[test]
+fn clamp_zoom_respects_zoom_limits() {
+    let rect = Rect::new(0., 0., 100., 100.);
+    let bounds = CameraBounds {
+        clamp_zoom: true,
+        ..CameraBounds::new(rect)
+    };
+
+    let mut camera = camera();
+    camera.set_zoom_limits(ZoomLimits::new([0.1, 0.1], [10., 10.]));
+    camera.set_bounds(Some(bounds));
+    assert!((camera.scale.x - 4.).abs() < 1e-4);
+    assert_inside(&camera, rect);
+
+    // The limits win; the view stays larger than the rect and is centred.
+    camera.set_zoom_limits(ZoomLimits::new([0.1, 0.1], [2., 2.]));
+    assert_eq!(camera.scale.x, 2.);
+    assert!((camera.position.x - 50.).abs() < EPSILON);
+
+    camera.zoom([0.5, 0.5]);
+    assert_eq!(camera.scale.x, 2.);
+}
+
+#[test]
+fn degenerate_rect_keeps_zoom_finite() {
+    for rect in [Rect::new(10., 10., 0., 100.), Rect::new(10., 10., 100., 0.)] {
+        let mut camera = camera();
+        camera.set_bounds(Some(CameraBounds {
+            clamp_zoom: true,
+            ..CameraBounds::new(rect)
+        }));
+        assert_eq!(camera.scale.x, 1.);
+        assert!(camera.position.x.is_finite() && camera.position.y.is_finite());
+        assert!((camera.position.x - (rect.x + rect.w / 2.)).abs() < EPSILON);
+    }
+}
@@ -0,0 +1,97 @@
+use camera2d_ggez::{Anchor, Camera, ConfineMode, PolygonConfiner, Viewport};
+
+const L_SHAPE: [[f32; 2]; 6] = [
+    [0., 0.],
+    [1000., 0.],
+    [1000., 400.],
+    [400., 400.],
+    [400., 1000.],
+    [0., 1000.],
+];
+
+const TARGETS: [[f32; 2]; 6] = [
+    [200., 200.],
+    [900., 200.],
+    [200., 900.],
+    [700., 700.],
+    [-500., -500.],
+    [1500., 1500.],
+];
+
+fn camera(rotation: f32, mode: ConfineMode) -> Camera {
+    let mut camera = Camera {
+        viewport: Viewport::new([200., 150.]),
+        anchor: Anchor::CENTER,
+        rotation,
+        ..Default::default()
+    };
+    camera.set_confiner(Some(PolygonConfiner::new(L_SHAPE, mode)));
+    camera
+}
+
+#[test]
+fn center_mode_keeps_position_inside() {
+    for rotation in [0., 0.4] {
+        let mut camera = camera(rotation, ConfineMode::Center);
+        let confiner = camera.confiner.clone().unwrap();
+        for target in TARGETS {
+            camera.set_position(target);
+            assert!(
+                confiner.contains(camera.position),
+                "{:?} escaped the polygon from {target:?}",
+                camera.position
+            );
+        }
+    }
+}
+
+#[test]
+fn view_mode_keeps_every_corner_inside() {
+    for rotation in [0., 0.4, -1.1] {
+        let mut camera = camera(rotation, ConfineMode::View);
+        let confiner = camera.confiner.clone().unwrap();
+        for target in TARGETS {
+            camera.set_position(target);
+            for corner in camera.visible_corners() {
+                assert!(
+                    confiner.contains(corner),
+                    "corner {corner:?} outside at rotation {rotation} from {target:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn view_mode_applies_on_every_mutation() {
+    let mut camera = camera(0., ConfineMode::View);
+    let confiner = camera.confiner.clone().unwrap();
+    camera.set_position([200., 200.]);
+
+    camera.move_by_world_coords([-2000., 0.]);
+    camera.rotate(0.7);
+    camera.zoom_center([0.8, 0.8]);
+
+    for corner in camera.visible_corners() {
+        assert!(confiner.contains(corner), "corner {corner:?} outside");
+    }
+}
+
+#[test]
+fn keeps_every_vertex_of_large_polygons() {
+    let circle: Vec<[f32; 2]> = (0..200)
+        .map(|i| {
+            let angle = i as f32 / 200. * std::f32::consts::TAU;
+            [angle.cos() * 1000., angle.sin() * 1000.]
+        })
+        .collect();
+    let confiner = PolygonConfiner::new(circle, ConfineMode::View);
+    assert_eq!(confiner.polygon().len(), 200);
+
+    let mut camera = camera(0.3, ConfineMode::View);
+    camera.set_confiner(Some(confiner.clone()));
+    camera.set_position([5000., 0.]);
+    for corner in camera.visible_corners() {
+        assert!(confiner.contains(corner), "corner {corner:?} outside");
+    }
+}
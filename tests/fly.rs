@@ -0,0 +1,80 @@
+use camera2d_ggez::{Anchor, Camera, FlyTo, Viewport};
+
+fn camera(x: f32, y: f32, zoom: f32) -> Camera {
+    let mut camera = Camera {
+        viewport: Viewport::new([800., 600.]),
+        anchor: Anchor::CENTER,
+        ..Default::default()
+    };
+    camera.set_position([x, y]);
+    camera.set_zoom([zoom, zoom]);
+    camera
+}
+
+fn fly(to: &Camera) -> FlyTo {
+    FlyTo::new(&camera(0., 0., 1.), to, FlyTo::DEFAULT_RHO)
+}
+
+#[test]
+fn endpoints_match_the_cameras() {
+    let to = camera(10000., 5000., 2.);
+    let fly = fly(&to);
+
+    let start = fly.sample(0.);
+    assert_eq!((start.position.x, start.position.y), (0., 0.));
+    assert_eq!(start.scale.x, 1.);
+    let end = fly.sample(1.);
+    assert_eq!((end.position.x, end.position.y), (10000., 5000.));
+    assert_eq!(end.scale.x, 2.);
+}
+
+#[test]
+fn path_is_continuous_up_to_both_ends() {
+    let fly = fly(&camera(10000., 5000., 2.));
+    let states = fly.states(1001);
+
+    // Each step moves the view by under 1% of its width and zooms by under 2%,
+    // including the steps onto the exact `from` and `to` cameras.
+    for pair in states.windows(2) {
+        let [a, b] = [&pair[0], &pair[1]];
+        let moved = (b.position.x - a.position.x).hypot(b.position.y - a.position.y);
+        assert!(moved * a.scale.x / 800. < 0.01, "{a:?} -> {b:?}");
+        assert!((b.scale.x / a.scale.x).ln().abs() < 0.02, "{a:?} -> {b:?}");
+    }
+}
+
+#[test]
+fn distant_targets_zoom_out_mid_flight() {
+    let fly = fly(&camera(10000., 5000., 2.));
+    let lowest = fly
+        .states(101)
+        .iter()
+        .map(|camera| camera.scale.x)
+        .fold(f32::MAX, f32::min);
+    assert!(lowest < 0.5, "{lowest}");
+
+    // A target already in view is reached without zooming out.
+    let fly = self::fly(&camera(100., 0., 1.));
+    for camera in fly.states(101) {
+        assert!(camera.scale.x > 0.99, "{}", camera.scale.x);
+    }
+}
+
+#[test]
+fn duration_grows_with_distance() {
+    let near = fly(&camera(1000., 0., 1.));
+    let far = fly(&camera(100000., 0., 1.));
+    assert!(far.duration(1.) > near.duration(1.));
+    assert!((far.duration(2.) - far.duration(1.) / 2.).abs() < 1e-4);
+
+    // Equal zooms make the path symmetric, so the midpoint comes at half the
+    // duration; it is checked against the view width, which peaks there.
+    let duration = far.duration(1.);
+    let halfway = far.at_time(duration / 2., 1.);
+    let view_width = 800. / halfway.scale.x;
+    assert!(
+        (halfway.position.x - 50000.).abs() < view_width * 1e-3,
+        "{:?}",
+        halfway.position
+    );
+}
@@ -0,0 +1,96 @@
+use camera2d_ggez::{Anchor, Camera, CameraFollow, FollowTarget, FollowZones, LookAhead, Viewport};
+use ggez::graphics::Rect;
+
+const FRAME_RATES: [f32; 3] = [30., 60., 144.];
+const WALL_TIME: f32 = 1.5;
+
+fn camera() -> Camera {
+    Camera {
+        viewport: Viewport::new([800., 600.]),
+        anchor: Anchor::CENTER,
+        ..Default::default()
+    }
+}
+
+// Runs `follow` for `WALL_TIME` seconds at every frame rate, with the target
+// given as a function of the elapsed time.
+fn run<F>(follow: CameraFollow, target: F) -> Vec<Camera>
+where
+    F: Fn(f32) -> FollowTarget,
+{
+    FRAME_RATES
+        .iter()
+        .map(|fps| {
+            let mut camera = camera();
+            let mut follow = follow;
+            let dt = 1. / fps;
+            let steps = (WALL_TIME * fps).round() as usize;
+            for step in 1..=steps {
+                follow.update(&mut camera, target(step as f32 * dt), dt);
+            }
+            camera
+        })
+        .collect()
+}
+
+fn assert_agree(cameras: &[Camera], position_tolerance: f32) {
+    let reference = &cameras[0];
+    for camera in &cameras[1..] {
+        let distance = (camera.position.x - reference.position.x)
+            .hypot(camera.position.y - reference.position.y);
+        assert!(
+            distance < position_tolerance,
+            "{:?} != {:?}",
+            camera.position,
+            reference.position
+        );
+        assert!((camera.scale.x - reference.scale.x).abs() < 1e-3);
+        assert!((camera.scale.y - reference.scale.y).abs() < 1e-3);
+        assert!((camera.rotation - reference.rotation).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn damping_is_frame_rate_independent() {
+    let target = FollowTarget {
+        zoom: Some([4., 4.].into()),
+        rotation: Some(3.),
+        ..FollowTarget::new([1000., -500.])
+    };
+    let cameras = run(CameraFollow::new(0.2), |_| target);
+    assert_agree(&cameras, 0.1);
+    assert!(cameras[0].position.x > 900.);
+}
+
+#[test]
+fn zones_are_frame_rate_independent() {
+    let mut follow = CameraFollow::new(0.3);
+    follow.zones = Some(FollowZones::new(
+        Rect::new(0.4, 0.4, 0.2, 0.2),
+        Rect::new(0.1, 0.1, 0.8, 0.8),
+    ));
+    let target = FollowTarget {
+        rotation: Some(0.5),
+        ..FollowTarget::new([2000., 300.])
+    };
+    let cameras = run(follow, |_| target);
+    // The zones are in screen space and turn with the camera while it catches up.
+    assert_agree(&cameras, 1.);
+
+    let [_, hard_limit] = follow.zones.unwrap().screen_rects(&cameras[0]);
+    let screen = cameras[0].world_to_screen_coords(target.position);
+    assert!(hard_limit.contains(screen));
+}
+
+#[test]
+fn look_ahead_is_frame_rate_independent() {
+    let mut follow = CameraFollow::new(0.1);
+    follow.look_ahead = Some(LookAhead::new(0.5, 150.));
+    let cameras = run(follow, |time| FollowTarget::new([time * 200., 0.]));
+    // A moving target is only sampled once per frame, so the lag may differ by a
+    // fraction of the distance it covers in one frame.
+    assert_agree(&cameras, 200. / FRAME_RATES[0] / 2.);
+
+    // The camera leads the target in the direction it moves.
+    assert!(cameras[0].position.x > WALL_TIME * 200.);
+}
@@ -0,0 +1,69 @@
+use camera2d_ggez::{Anchor, Camera, FitMode, Viewport};
+use ggez::graphics::Rect;
+
+const FIT_MODES: [FitMode; 5] = [
+    FitMode::Contain,
+    FitMode::Cover,
+    FitMode::FitWidth,
+    FitMode::FitHeight,
+    FitMode::Stretch,
+];
+
+fn camera() -> Camera {
+    Camera {
+        viewport: Viewport::new([800., 600.]),
+        anchor: Anchor::CENTER,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn contain_and_cover_fit_the_rect() {
+    let mut camera = camera();
+    let rect = Rect::new(-50., 20., 200., 100.);
+
+    camera.frame_rect(rect, 0., FitMode::Contain);
+    assert_eq!((camera.scale.x, camera.scale.y), (4., 4.));
+    assert_eq!((camera.position.x, camera.position.y), (50., 70.));
+
+    camera.frame_rect(rect, 50., FitMode::Cover);
+    assert_eq!((camera.scale.x, camera.scale.y), (5., 5.));
+}
+
+#[test]
+fn degenerate_rects_keep_the_camera_finite() {
+    for rect in [
+        Rect::new(10., 10., 0., 0.),
+        Rect::new(10., 10., 100., 0.),
+        Rect::new(10., 10., 0., 100.),
+    ] {
+        for rotation in [0., 0.3] {
+            for fit_mode in FIT_MODES {
+                let mut camera = camera();
+                camera.set_rotation(rotation);
+                camera.frame_rect(rect, 0., fit_mode);
+
+                let context = format!("{rect:?} {rotation} {fit_mode:?}");
+                assert!(
+                    camera.scale.x.is_finite() && camera.scale.x > 0.,
+                    "{context}"
+                );
+                assert!(
+                    camera.scale.y.is_finite() && camera.scale.y > 0.,
+                    "{context}"
+                );
+                assert!(
+                    (camera.position.x - (rect.x + rect.w / 2.)).abs() < 1e-3
+                        && (camera.position.y - (rect.y + rect.h / 2.)).abs() < 1e-3,
+                    "{context}: {:?}",
+                    camera.position
+                );
+            }
+        }
+    }
+
+    // A line still fits along its length.
+    let mut camera = camera();
+    camera.frame_rect(Rect::new(10., 10., 100., 0.), 0., FitMode::Contain);
+    assert_eq!(camera.scale.x, 8.);
+}
@@ -0,0 +1,91 @@
+use camera2d_ggez::{CameraPath, PathError, PathPoint, SplineKind};
+use ggez::mint::Point2;
+
+fn points(positions: &[[f32; 2]]) -> Vec<PathPoint> {
+    positions
+        .iter()
+        .map(|&position| PathPoint::new(position))
+        .collect()
+}
+
+fn paths() -> [CameraPath; 2] {
+    let positions = [[0., 0.], [100., 0.], [100., 100.], [300., 100.]];
+    [SplineKind::CatmullRom, SplineKind::Bezier]
+        .map(|kind| CameraPath::new(kind, points(&positions)).unwrap())
+}
+
+fn distance(a: Point2<f32>, b: Point2<f32>) -> f32 {
+    (b.x - a.x).hypot(b.y - a.y)
+}
+
+#[test]
+fn equal_distances_cover_equal_chords() {
+    for path in paths() {
+        let step = path.length() / 50.;
+        let positions: Vec<_> = (0..=50)
+            .map(|i| path.sample_distance(step * i as f32).position)
+            .collect();
+        // The arc-length table is sampled, so steps match to within a few percent.
+        for pair in positions.windows(2) {
+            let chord = distance(pair[0], pair[1]);
+            assert!(
+                chord > step * 0.97 && chord < step * 1.03,
+                "{:?}: {chord} vs {step}",
+                path.kind()
+            );
+        }
+    }
+}
+
+#[test]
+fn time_samples_are_distance_samples_at_speed() {
+    for path in paths() {
+        let speed = 120.;
+        for time in [0., 0.4, 1.3, 2.] {
+            let by_time = path.sample_time(time, speed).position;
+            let by_distance = path.sample_distance(time * speed).position;
+            assert_eq!(by_time, by_distance);
+        }
+
+        // Samples past either end hold the endpoints.
+        let end = path.sample_time(path.duration(speed), speed).position;
+        assert!(distance(end, Point2 { x: 300., y: 100. }) < 1e-3);
+        assert_eq!(path.sample_time(99., speed).position, end);
+        assert_eq!(
+            path.sample_time(-1., speed).position,
+            Point2 { x: 0., y: 0. }
+        );
+    }
+}
+
+#[test]
+fn rejects_point_counts_it_cannot_use() {
+    assert_eq!(
+        CameraPath::new(SplineKind::CatmullRom, Vec::new()).unwrap_err(),
+        PathError::NoPoints
+    );
+    assert_eq!(
+        CameraPath::new(SplineKind::Bezier, points(&[[0., 0.]; 5])).unwrap_err(),
+        PathError::BezierPointCount(5)
+    );
+    assert!(CameraPath::new(SplineKind::Bezier, points(&[[0., 0.]; 7])).is_ok());
+
+    let still = CameraPath::new(SplineKind::Bezier, points(&[[4., 2.]])).unwrap();
+    assert_eq!(still.length(), 0.);
+    assert_eq!(still.sample_distance(10.).position, Point2 { x: 4., y: 2. });
+}
+
+#[test]
+fn polyline_point_count_is_bounded() {
+    for path in paths() {
+        assert_eq!(path.polyline(path.length() / 10.).len(), 11);
+        for spacing in [0., -1., f32::NAN] {
+            let polyline = path.polyline(spacing);
+            assert!(
+                polyline.len() > 1 && polyline.len() <= 4097,
+                "{}",
+                polyline.len()
+            );
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use camera2d_ggez::{Camera, Easing, Keyframe, Timeline, TimelineEvent, TimelinePlayer};
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 1e-3, "{a} != {b}");
+}
+
+fn key(time: f32, x: f32) -> Keyframe {
+    Keyframe {
+        position: Some([x, 0.].into()),
+        ..Keyframe::new(time)
+    }
+}
+
+// 0 ── hold ── 1 ── QuadIn ──> 2 ── cut at 3 ── 4
+fn timeline() -> Timeline {
+    Timeline::new(
+        vec![
+            Keyframe {
+                easing: Easing::QuadIn,
+                ..key(2., 100.)
+            },
+            Keyframe {
+                hold: 1.,
+                zoom: Some([1., 1.].into()),
+                ..key(0., 0.)
+            },
+            Keyframe {
+                cut: true,
+                ..key(3., 500.)
+            },
+            Keyframe {
+                zoom: Some([4., 4.].into()),
+                ..Keyframe::new(4.)
+            },
+        ],
+        vec![
+            TimelineEvent::new(2.5, "explosion"),
+            TimelineEvent::new(1., "start"),
+        ],
+    )
+}
+
+fn names(events: Vec<&TimelineEvent>) -> Vec<String> {
+    events.into_iter().map(|event| event.name.clone()).collect()
+}
+
+#[test]
+fn holds_easing_and_cuts() {
+    let timeline = timeline();
+    let base = Camera::default();
+    let x = |time: f32| timeline.camera_at(&base, time).position.x;
+
+    assert_eq!(timeline.duration(), 4.);
+    assert_eq!(x(-1.), 0.);
+    assert_eq!(x(0.5), 0.);
+    assert_eq!(x(1.), 0.);
+    assert_close(x(1.5), 100. * 0.25);
+    assert_close(x(2.), 100.);
+    assert_eq!(x(2.99), 100.);
+    assert_eq!(x(3.), 500.);
+    assert_eq!(x(10.), 500.);
+
+    // Zoom only has keys at 0 (held for a second) and 4, and blends in log space.
+    assert_close(timeline.camera_at(&base, 2.5).scale.x, 2.);
+}
+
+#[test]
+fn unkeyed_properties_come_from_the_base_camera() {
+    let mut base = Camera::default();
+    base.set_rotation(0.7);
+    base.set_position([0., 42.]);
+
+    let camera = timeline().camera_at(&base, 1.5);
+    assert_eq!(camera.rotation, 0.7);
+    assert_eq!(camera.position.y, 0.);
+}
+
+#[test]
+fn player_delivers_events_once_per_crossing() {
+    let mut camera = Camera::default();
+    let mut player = TimelinePlayer::new(timeline());
+
+    assert!(player.update(&mut camera, 1.).is_empty());
+    player.play();
+
+    let mut fired = Vec::new();
+    for _ in 0..60 {
+        fired.extend(names(player.update(&mut camera, 0.1)));
+    }
+    assert_eq!(fired, ["start", "explosion"]);
+    assert!(player.is_finished());
+    assert!(!player.is_playing());
+    assert_eq!(camera.position.x, 500.);
+
+    player.speed = -1.;
+    player.play();
+    let mut fired = Vec::new();
+    for _ in 0..60 {
+        fired.extend(names(player.update(&mut camera, 0.1)));
+    }
+    assert_eq!(fired, ["explosion", "start"]);
+    assert!(player.is_finished());
+    assert_eq!(camera.position.x, 0.);
+}
+
+#[test]
+fn seek_speed_and_pause() {
+    let mut camera = Camera::default();
+    let mut player = TimelinePlayer::new(timeline());
+    player.seek(2.5);
+    player.play();
+
+    // Events exactly at the seek target fire on the next step.
+    assert_eq!(names(player.update(&mut camera, 0.)), ["explosion"]);
+
+    player.seek(1.5);
+    player.speed = 2.;
+    assert_eq!(names(player.update(&mut camera, 0.5)), ["explosion"]);
+    assert_close(player.time(), 2.5);
+
+    player.pause();
+    assert!(player.update(&mut camera, 1.).is_empty());
+    assert_close(player.time(), 2.5);
+
+    player.speed = 0.;
+    player.play();
+    player.update(&mut camera, 1.);
+    assert!(player.is_playing());
+    assert_close(player.time(), 2.5);
+}
+
+#[cfg(feature = "ron")]
+#[test]
+fn loads_from_ron() {
+    let timeline = Timeline::from_ron(
+        r#"(
+            keyframes: [
+                (time: 2.0, position: Some((100.0, 0.0)), easing: QuadIn),
+                (time: 0.0, position: Some((0.0, 0.0)), zoom: Some((1.0, 1.0)), hold: 1.0),
+                (time: 3.0, position: Some((500.0, 0.0)), cut: true),
+                (time: 4.0, zoom: Some((4.0, 4.0))),
+            ],
+            events: [(time: 2.5, name: "explosion"), (time: 1.0, name: "start")],
+        )"#,
+    )
+    .unwrap();
+    assert_eq!(timeline, self::timeline());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn loads_from_json() {
+    let timeline = Timeline::from_json(
+        r#"{
+            "keyframes": [
+                {"time": 2.0, "position": [100.0, 0.0], "easing": "QuadIn"},
+                {"time": 0.0, "position": [0.0, 0.0], "zoom": [1.0, 1.0], "hold": 1.0},
+                {"time": 3.0, "position": [500.0, 0.0], "cut": true},
+                {"time": 4.0, "zoom": [4.0, 4.0]}
+            ],
+            "events": [{"time": 2.5, "name": "explosion"}, {"time": 1.0, "name": "start"}]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(timeline, self::timeline());
+}
+
+#[cfg(feature = "ron")]
+#[test]
+fn nested_timelines_are_sorted() {
+    #[derive(serde::Deserialize)]
+    struct Cutscene {
+        timeline: Timeline,
+    }
+
+    let cutscene: Cutscene = ron::from_str(
+        r#"(timeline: (
+            keyframes: [(time: 2.0), (time: 0.0), (time: 1.0)],
+            events: [(time: 1.5, name: "b"), (time: 0.5, name: "a")],
+        ))"#,
+    )
+    .unwrap();
+    let times: Vec<f32> = cutscene
+        .timeline
+        .keyframes()
+        .iter()
+        .map(|keyframe| keyframe.time)
+        .collect();
+    assert_eq!(times, [0., 1., 2.]);
+    assert_eq!(
+        names(cutscene.timeline.events().iter().collect()),
+        ["a", "b"]
+    );
+}
@@ -0,0 +1,102 @@
+use std::{cell::RefCell, rc::Rc};
+
+use camera2d_ggez::{Camera, CameraTweener, Easing, Tween};
+
+fn move_to(x: f32, duration: f32) -> Tween {
+    Tween {
+        position: Some([x, 0.].into()),
+        ..Tween::new(duration, Easing::Linear)
+    }
+}
+
+#[test]
+fn chained_tweens_run_in_order_and_carry_leftover_time() {
+    let mut camera = Camera::default();
+    let mut tweener = CameraTweener::new();
+    let first = tweener.play(move_to(100., 1.));
+    let second = tweener.then(Tween {
+        zoom: Some([4., 4.].into()),
+        ..Tween::new(1., Easing::Linear)
+    });
+
+    assert!(tweener.update(&mut camera, 0.5).is_empty());
+    assert!((camera.position.x - 50.).abs() < 1e-4);
+    assert_eq!(tweener.progress(), Some(0.5));
+
+    // The 0.25 s past the end of the first tween is spent on the second one,
+    // whose zoom interpolates in log space.
+    assert_eq!(tweener.update(&mut camera, 0.75), vec![first]);
+    assert_eq!(camera.position.x, 100.);
+    assert!((camera.scale.x - 4f32.powf(0.25)).abs() < 1e-4);
+    assert!(tweener.is_pending(second));
+
+    assert_eq!(tweener.update(&mut camera, 1.), vec![second]);
+    assert_eq!(camera.scale.x, 4.);
+    assert!(!tweener.is_active());
+}
+
+#[test]
+fn one_update_can_finish_several_tweens() {
+    let mut camera = Camera::default();
+    let mut tweener = CameraTweener::new();
+    let ids = [
+        tweener.then(move_to(10., 0.1)),
+        tweener.then(move_to(20., 0.1)),
+        tweener.then(move_to(30., 0.)),
+        tweener.then(move_to(40., 1.)),
+    ];
+
+    assert_eq!(tweener.update(&mut camera, 0.3), ids[..3].to_vec());
+    assert!((camera.position.x - 31.).abs() < 1e-3);
+}
+
+#[test]
+fn cancellation() {
+    let mut camera = Camera::default();
+    let mut tweener = CameraTweener::new();
+    let first = tweener.play(move_to(100., 1.));
+    let second = tweener.then(move_to(200., 1.));
+    let third = tweener.then(move_to(300., 1.));
+
+    tweener.cancel_tween(second);
+    assert!(!tweener.is_pending(second));
+    tweener.update(&mut camera, 0.5);
+    tweener.cancel_tween(first);
+    assert!(tweener.progress().is_none());
+
+    // Cancelling leaves the camera where it was; the next tween starts from there.
+    assert_eq!(tweener.update(&mut camera, 0.5), Vec::new());
+    assert!((camera.position.x - 175.).abs() < 1e-3);
+    assert!(tweener.is_pending(third));
+
+    let replacement = tweener.play(move_to(0., 1.));
+    assert!(!tweener.is_pending(third));
+    assert!(tweener.is_pending(replacement));
+
+    tweener.cancel();
+    assert!(!tweener.is_active());
+    assert!(tweener.update(&mut camera, 1.).is_empty());
+}
+
+#[test]
+fn on_complete_runs_once_with_the_final_camera() {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let mut camera = Camera::default();
+    let mut tweener = CameraTweener::new();
+
+    let recorded = Rc::clone(&calls);
+    tweener.play(Tween {
+        on_complete: Some(Box::new(move |camera: &mut Camera| {
+            recorded.borrow_mut().push(camera.position.x);
+            camera.set_rotation(1.);
+        })),
+        ..move_to(100., 1.)
+    });
+
+    for _ in 0..10 {
+        tweener.update(&mut camera, 0.25);
+    }
+
+    assert_eq!(*calls.borrow(), vec![100.]);
+    assert_eq!(camera.rotation, 1.);
+}
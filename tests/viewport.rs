@@ -0,0 +1,131 @@
+use camera2d_ggez::{Anchor, Camera, ScaleMode, Viewport, VirtualResolution};
+use ggez::{graphics::Rect, mint::Point2};
+
+fn assert_close(a: Point2<f32>, b: Point2<f32>) {
+    assert!(
+        (a.x - b.x).abs() < 1e-3 && (a.y - b.y).abs() < 1e-3,
+        "{a:?} != {b:?}"
+    );
+}
+
+#[test]
+fn window_and_drawable_round_trip() {
+    let viewport = Viewport::from_window_size([800., 600.], 2.);
+    assert_eq!(viewport.size.x, 1600.);
+    assert_eq!(viewport.window_size().y, 600.);
+
+    let window = Point2 { x: 123.5, y: 77.25 };
+    let drawable = viewport.window_to_drawable(window);
+    assert_close(drawable, Point2 { x: 247., y: 154.5 });
+    assert_close(viewport.drawable_to_window(drawable), window);
+}
+
+#[test]
+fn window_and_world_round_trip() {
+    let mut camera = Camera {
+        viewport: Viewport::from_window_size([800., 600.], 2.),
+        anchor: Anchor::CENTER,
+        rotation: 0.3,
+        ..Default::default()
+    };
+    camera.set_zoom([1.5, 1.5]);
+    camera.set_position([40., -25.]);
+
+    // The window centre maps to the camera position regardless of the scale factor.
+    assert_close(camera.window_to_world_coords([400., 300.]), camera.position);
+
+    for window in [[0., 0.], [800., 600.], [123., 456.]] {
+        let world = camera.window_to_world_coords(window);
+        assert_close(camera.world_to_window_coords(world), window.into());
+        assert_close(
+            camera.world_to_screen_coords(world),
+            camera.viewport.window_to_drawable(window),
+        );
+    }
+}
+
+fn letterboxed(mode: ScaleMode) -> Camera {
+    let mut viewport = Viewport::new([1000., 700.]);
+    viewport.set_origin([20., 10.]);
+    let mut camera = Camera {
+        viewport,
+        ..Default::default()
+    };
+    camera.set_virtual_resolution(Some(VirtualResolution::new([400., 200.], mode)));
+    camera
+}
+
+#[test]
+fn content_rect_and_bars_per_scale_mode() {
+    // Fit scales by 2.5 to the full width and bars the leftover height.
+    let camera = letterboxed(ScaleMode::Fit);
+    assert_eq!(camera.content_rect(), Rect::new(20., 110., 1000., 500.));
+    assert_eq!(
+        camera.letterbox_bars(),
+        [
+            Rect::new(20., 10., 1000., 100.),
+            Rect::new(20., 610., 1000., 100.),
+        ]
+    );
+
+    // Fill scales by 3.5 and crops the sides instead.
+    let camera = letterboxed(ScaleMode::Fill);
+    assert_eq!(camera.content_rect(), Rect::new(-180., 10., 1400., 700.));
+    assert_eq!(
+        camera.visible_screen_rect(),
+        Rect::new(20., 10., 1000., 700.)
+    );
+    assert!(camera.letterbox_bars().is_empty());
+
+    let camera = letterboxed(ScaleMode::Stretch);
+    assert_eq!(camera.screen_scale().x, 2.5);
+    assert_eq!(camera.screen_scale().y, 3.5);
+    assert_eq!(camera.content_rect(), Rect::new(20., 10., 1000., 700.));
+    assert!(camera.letterbox_bars().is_empty());
+
+    // Expand keeps Fit's scale and shows more of the world along the long axis.
+    let camera = letterboxed(ScaleMode::Expand);
+    assert_eq!(camera.logical_size().y, 280.);
+    assert_eq!(camera.content_rect(), Rect::new(20., 10., 1000., 700.));
+    assert!(camera.letterbox_bars().is_empty());
+}
+
+#[test]
+fn screen_and_world_round_trip_inside_letterbox_bars() {
+    let mut viewport = Viewport::new([1000., 700.]);
+    viewport.set_origin([20., 10.]);
+    let mut camera = Camera {
+        viewport,
+        anchor: Anchor::CENTER,
+        ..Default::default()
+    };
+    camera.set_pixel_perfect(true);
+    camera.set_virtual_resolution(Some(VirtualResolution::new([320., 180.], ScaleMode::Fit)));
+    camera.set_position([12., -7.]);
+
+    // An integer scale of 3 leaves bars on every side.
+    let content = camera.content_rect();
+    assert_eq!(content, Rect::new(40., 90., 960., 540.));
+    assert_eq!(
+        camera.letterbox_bars(),
+        [
+            Rect::new(20., 10., 1000., 80.),
+            Rect::new(20., 630., 1000., 80.),
+            Rect::new(20., 90., 20., 540.),
+            Rect::new(1000., 90., 20., 540.),
+        ]
+    );
+
+    assert_close(
+        camera.screen_to_world_coords([content.x, content.y]),
+        Point2 { x: -148., y: -97. },
+    );
+    assert_close(
+        camera.screen_to_world_coords([content.right(), content.bottom()]),
+        Point2 { x: 172., y: 83. },
+    );
+    for screen in [[40., 90.], [517., 333.], [1000., 630.]] {
+        let world = camera.screen_to_world_coords(screen);
+        assert_close(camera.world_to_screen_coords(world), screen.into());
+    }
+}
@@ -0,0 +1,131 @@
+use camera2d_ggez::{Camera, FitMode, ResizePolicy, Viewport, ZoomLimits};
+use ggez::{graphics::Rect, mint::Point2};
+
+fn assert_close(a: Point2<f32>, b: Point2<f32>) {
+    assert!(
+        (a.x - b.x).abs() < 1e-2 && (a.y - b.y).abs() < 1e-2,
+        "{a:?} != {b:?}"
+    );
+}
+
+fn camera() -> Camera {
+    let mut viewport = Viewport::new([800., 600.]);
+    viewport.set_origin([100., 50.]);
+    let mut camera = Camera::default();
+    camera.set_viewport(viewport);
+    camera.set_position([30., -20.]);
+    camera.set_rotation(0.4);
+    camera
+}
+
+#[test]
+fn zoom_center_keeps_the_viewport_center_fixed() {
+    let mut camera = camera();
+    let center = camera.viewport.center();
+    assert_close(center, Point2 { x: 500., y: 350. });
+
+    let world = camera.screen_to_world_coords(center);
+    camera.zoom_center([2., 2.]);
+    assert_eq!(camera.scale.x, 2.);
+    assert_close(camera.screen_to_world_coords(center), world);
+
+    camera.zoom_center([0.25, 0.25]);
+    assert_close(camera.screen_to_world_coords(center), world);
+}
+
+#[test]
+fn pan_follows_the_screen_without_a_window() {
+    let mut camera = camera();
+    camera.set_zoom([2., 2.]);
+    let grabbed = camera.screen_to_world_coords([300., 200.]);
+
+    camera.move_by_screen_coords([50., -30.]);
+    assert_close(
+        camera.world_to_screen_coords(grabbed),
+        Point2 { x: 350., y: 170. },
+    );
+}
+
+fn limited() -> Camera {
+    let mut camera = camera();
+    camera.set_zoom_limits(ZoomLimits::new([0.5, 0.5], [4., 4.]));
+    camera
+}
+
+fn assert_within_limits(camera: &Camera) {
+    let limits = camera.zoom_limits;
+    for (scale, min, max) in [
+        (camera.scale.x, limits.min.x, limits.max.x),
+        (camera.scale.y, limits.min.y, limits.max.y),
+    ] {
+        assert!(
+            scale >= min && scale <= max,
+            "{scale} outside [{min}, {max}]"
+        );
+    }
+}
+
+#[test]
+fn every_zoom_path_respects_the_limits() {
+    let paths: [fn(&mut Camera); 9] = [
+        |camera| camera.set_zoom([100., 0.01]),
+        |camera| camera.zoom([50., 50.]),
+        |camera| camera.zoom_center([0.01, 0.01]),
+        |camera| camera.zoom_at_screen_coords([10., 10.], [20., 20.]),
+        |camera| camera.zoom_to_next_level([10., 10.], &[0.1, 0.25, 1., 8., 16.]),
+        |camera| camera.zoom_to_previous_level([10., 10.], &[0.1, 0.25, 1., 8., 16.]),
+        |camera| camera.set_zoom_limits(ZoomLimits::new([2., 2.], [3., 3.])),
+        |camera| {
+            camera.resize_policy = ResizePolicy::KeepWidth;
+            camera.handle_resize([800., 600.], [8000., 6000.]);
+        },
+        |camera| camera.frame_rect(Rect::new(0., 0., 1., 1.), 0., FitMode::Contain),
+    ];
+    // Repeated calls walk past every level and keep growing a resize.
+    for path in paths {
+        let mut camera = limited();
+        for _ in 0..5 {
+            path(&mut camera);
+            assert_within_limits(&camera);
+        }
+    }
+}
+
+#[test]
+fn zooming_at_a_point_keeps_it_under_the_cursor() {
+    let cursor = Point2 { x: 620., y: 140. };
+    let paths: [fn(&mut Camera, Point2<f32>); 4] = [
+        |camera, cursor| camera.zoom_at_screen_coords(cursor, [1.7, 1.7]),
+        |camera, cursor| camera.zoom_at_screen_coords(cursor, [100., 100.]),
+        |camera, cursor| camera.zoom_to_next_level(cursor, &[0.5, 1., 2., 4.]),
+        |camera, cursor| camera.zoom_to_previous_level(cursor, &[0.5, 1., 2., 4.]),
+    ];
+    for path in paths {
+        let mut camera = limited();
+        for _ in 0..5 {
+            let world = camera.screen_to_world_coords(cursor);
+            path(&mut camera, cursor);
+            assert_within_limits(&camera);
+            assert_close(camera.screen_to_world_coords(cursor), world);
+        }
+    }
+}
+
+#[test]
+fn zoom_levels_snap_each_axis() {
+    let levels = [1., 2., 4., 8.];
+    let mut camera = camera();
+    camera.set_zoom([1.5, 3.]);
+
+    camera.zoom_to_next_level([0., 0.], &levels);
+    assert_eq!((camera.scale.x, camera.scale.y), (2., 4.));
+    camera.zoom_to_next_level([0., 0.], &levels);
+    assert_eq!((camera.scale.x, camera.scale.y), (4., 8.));
+
+    // The y axis has no level left, so only x moves.
+    camera.zoom_to_next_level([0., 0.], &levels);
+    assert_eq!((camera.scale.x, camera.scale.y), (8., 8.));
+
+    camera.zoom_to_previous_level([0., 0.], &levels);
+    assert_eq!((camera.scale.x, camera.scale.y), (4., 4.));
+}